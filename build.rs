@@ -0,0 +1,100 @@
+//! Generates the registry of scaffolded solutions that the `advent_of_code` binary links in, so
+//! `cargo all` and `cargo time` can run them in-process.
+use std::{
+    env,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/bin");
+    println!("cargo:rerun-if-changed=src/template/event.rs");
+    println!("cargo:rerun-if-env-changed=AOC_YEAR");
+
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("registry.rs");
+
+    // NOTE: with `dhat-heap`, every solution declares its own global allocator.
+    let days = if env::var_os("CARGO_FEATURE_DHAT_HEAP").is_some() {
+        vec![]
+    } else {
+        find_days(
+            &manifest_dir.join("src").join("bin"),
+            configured_year(env::var("AOC_YEAR").ok().as_deref()),
+        )
+    };
+
+    fs::write(out_path, generate(&days)).unwrap();
+}
+
+include!("src/template/event.rs");
+
+/// Collects the solution files that can be linked into the main binary.
+fn find_days(bin_dir: &Path, year: u16) -> Vec<(u8, PathBuf)> {
+    let Ok(entries) = fs::read_dir(bin_dir) else {
+        return vec![];
+    };
+
+    let mut days: Vec<(u8, PathBuf)> = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()? != "rs" {
+                return None;
+            }
+
+            // solution files are named like `2025_01.rs`.
+            let (file_year, day) = path.file_stem()?.to_str()?.split_once('_')?;
+            let day: u8 = day.parse().ok()?;
+            if file_year.parse() != Ok(year) || !(1..=last_day(year)).contains(&day) {
+                return None;
+            }
+
+            let source = fs::read_to_string(&path).ok()?;
+
+            // solutions that bring their own allocator can only run as a separate binary.
+            if source.contains("global_allocator") || !source.contains("solution!") {
+                return None;
+            }
+
+            Some((day, path))
+        })
+        .collect();
+
+    days.sort_unstable();
+    days
+}
+
+fn generate(days: &[(u8, PathBuf)]) -> String {
+    let mut modules = String::new();
+    let mut entries = String::new();
+
+    for (day, path) in days {
+        writeln!(
+            modules,
+            "#[cfg(not(test))]\n#[allow(dead_code, clippy::all)]\n#[path = {:?}]\nmod day_{day:02};",
+            path.display().to_string()
+        )
+        .unwrap();
+        writeln!(
+            entries,
+            "        (advent_of_code::day!({day}), day_{day:02}::run as _),"
+        )
+        .unwrap();
+    }
+
+    format!(
+        r"{modules}
+#[cfg(not(test))]
+pub fn registry() -> advent_of_code::template::registry::Registry {{
+    advent_of_code::template::registry::Registry::new(vec![
+{entries}    ])
+}}
+
+#[cfg(test)]
+pub fn registry() -> advent_of_code::template::registry::Registry {{
+    advent_of_code::template::registry::Registry::default()
+}}
+"
+    )
+}
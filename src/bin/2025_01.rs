@@ -1,28 +1,42 @@
-advent_of_code::solution!(1);
+use advent_of_code::template::Parse;
 
+advent_of_code::solution!(1, parse = Rotations::parse);
+
+pub struct Rotations(Vec<Move>);
+
+impl Parse for Rotations {
+    type Error = String;
+
+    fn parse(input: &str) -> Result<Self, Self::Error> {
+        input
+            .lines()
+            .map(Move::parse)
+            .collect::<Result<_, _>>()
+            .map(Rotations)
+    }
+}
+
+#[derive(Clone, Copy)]
 struct Move {
     direction: Direction,
     clicks: u64,
 }
 impl Move {
-    fn parse(line: &str) -> Option<Self> {
-        let dir = match Direction::parse(line) {
-            Some(dir) => dir,
-            None => panic!("Error parsing direction for line {}", line),
-        };
-
-        let clicks = line
-            .split_at(1)
-            .1
+    fn parse(line: &str) -> Result<Self, String> {
+        let dir = Direction::parse(line)
+            .ok_or_else(|| format!("Error parsing direction for line {}", line))?;
+
+        let clicks = line[1..]
             .parse()
-            .unwrap_or_else(|e| panic!("Error parsing number: {}", e));
+            .map_err(|e| format!("Error parsing number for line {}: {}", line, e))?;
 
-        Some(Move {
+        Ok(Move {
             direction: dir,
             clicks,
         })
     }
 }
+#[derive(Clone, Copy)]
 enum Direction {
     Left,
     Right,
@@ -78,15 +92,11 @@ impl Dial {
     }
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
+pub fn part_one(input: &Rotations) -> Option<u64> {
     let mut dial = Dial::new();
     let mut passwd = 0;
-    for line in input.lines() {
-        if let Some(mv) = Move::parse(line) {
-            dial.adjust(mv);
-        } else {
-            println!("Error on line: {}", line)
-        }
+    for &mv in &input.0 {
+        dial.adjust(mv);
         if dial.point == 0 {
             passwd += 1;
         }
@@ -95,14 +105,10 @@ pub fn part_one(input: &str) -> Option<u64> {
     Some(passwd)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
+pub fn part_two(input: &Rotations) -> Option<u64> {
     let mut dial = Dial::new();
-    for line in input.lines() {
-        if let Some(mv) = Move::parse(line) {
-            dial.adjust(mv);
-        } else {
-            println!("Error on line: {}", line)
-        }
+    for &mv in &input.0 {
+        dial.adjust(mv);
     }
 
     Some(dial.passwd)
@@ -114,13 +120,17 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(
+            &Rotations::parse(&advent_of_code::template::read_file("examples", YEAR, DAY)).unwrap(),
+        );
         assert_eq!(result, Some(3));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(
+            &Rotations::parse(&advent_of_code::template::read_file("examples", YEAR, DAY)).unwrap(),
+        );
         assert_eq!(result, Some(6));
     }
 }
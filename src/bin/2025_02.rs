@@ -0,0 +1,92 @@
+use advent_of_code::interval_set::IntervalSet;
+
+advent_of_code::solution!(2);
+
+fn parse_id_ranges(input: &str) -> IntervalSet {
+    input
+        .trim()
+        .split(",")
+        .map(|raw_range| {
+            let (start, end) = raw_range
+                .trim()
+                .split_once("-")
+                .unwrap_or_else(|| panic!("Error parsing range {}", raw_range));
+
+            let parse = |n: &str| {
+                n.parse::<u64>()
+                    .unwrap_or_else(|e| panic!("Invalid raw_range: {}\nDetails: {}", raw_range, e))
+            };
+
+            parse(start)..=parse(end)
+        })
+        .collect()
+}
+
+/// Returns the ids up to `max` that consist of a sequence of digits repeated `n` times, for every
+/// `n` accepted by `is_repetition`. E.g. `123123` is the sequence `123` repeated twice.
+fn repeated_ids(max: u64, is_repetition: impl Fn(u32) -> bool) -> Vec<u64> {
+    let mut ids = Vec::new();
+    let max_digits = max.checked_ilog10().unwrap_or(0) + 1;
+
+    for digits in 2..=max_digits {
+        for seq_len in (1..digits).filter(|len| digits % len == 0) {
+            if !is_repetition(digits / seq_len) {
+                continue;
+            }
+
+            // e.g. 1001001 for a sequence of 3 digits repeated 3 times.
+            let multiplier = (0..digits / seq_len)
+                .map(|i| 10u64.pow(i * seq_len))
+                .sum::<u64>();
+
+            for seq in 10u64.pow(seq_len - 1)..10u64.pow(seq_len) {
+                match seq.checked_mul(multiplier) {
+                    Some(id) if id <= max => ids.push(id),
+                    _ => break,
+                }
+            }
+        }
+    }
+
+    // e.g. 1111 is both `1` repeated 4 times and `11` repeated twice.
+    ids.sort_unstable();
+    ids.dedup();
+    ids
+}
+
+fn sum_invalid_ids(input: &str, is_repetition: impl Fn(u32) -> bool) -> u64 {
+    let ranges = parse_id_ranges(input);
+    let Some(max) = ranges.max() else {
+        return 0;
+    };
+
+    repeated_ids(max, is_repetition)
+        .into_iter()
+        .filter(|&id| ranges.contains(id))
+        .sum()
+}
+
+pub fn part_one(input: &str) -> Option<u64> {
+    Some(sum_invalid_ids(input, |n| n == 2))
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    Some(sum_invalid_ids(input, |n| n >= 2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(1227775554));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(4174379265));
+    }
+}
@@ -1,3 +1,5 @@
+use advent_of_code::parse::digits;
+
 advent_of_code::solution!(3);
 
 #[derive(Debug)]
@@ -11,34 +13,24 @@ struct BatteryBank {
 
 impl BatteryBank {
     fn parse(batteries: &str) -> Self {
-        let mut bank = Vec::new();
-        for battery in batteries.chars() {
-            bank.push(Battery {
-                joltage: battery
-                    .to_string()
-                    .parse()
-                    .unwrap_or_else(|_| panic!("Error parsing digit: \"{}\"", battery)),
-            });
+        BatteryBank {
+            batteries: digits(batteries)
+                .map(|joltage| Battery { joltage })
+                .collect(),
         }
-        BatteryBank { batteries: bank }
     }
 
     fn get_max_joltaje(&self, digits_to_take: usize) -> u64 {
         let mut start_pos = 0;
-        let mut digits = String::new();
-        for _ in 0..digits_to_take {
+        let mut joltage = 0;
+        for taken in 0..digits_to_take {
             let (digit, next_satrt_pos) =
-                self.get_bigges_joltaje_bat(start_pos, digits_to_take - digits.len());
-            digits.push_str(&digit.to_string());
+                self.get_bigges_joltaje_bat(start_pos, digits_to_take - taken);
+            joltage = joltage * 10 + u64::from(digit);
             start_pos = next_satrt_pos + 1;
         }
 
-        digits.parse().unwrap_or_else(|_| {
-            panic!(
-                "Error calculating bat joltaje with \"{}\" as joltaje",
-                digits
-            )
-        })
+        joltage
     }
 
     fn get_bigges_joltaje_bat(&self, start_pos: usize, needed_positions: usize) -> (u8, usize) {
@@ -88,13 +80,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, Some(357));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
         assert_eq!(result, None);
     }
 }
@@ -0,0 +1,73 @@
+use advent_of_code::grid::{Grid, Pos};
+
+advent_of_code::solution!(4);
+
+fn parse_grid(input: &str) -> Grid<bool> {
+    Grid::parse(input, |c| c == '@')
+}
+
+fn count_adjacent_papers(grid: &Grid<bool>, pos: Pos) -> usize {
+    grid.neighbours_8(pos).filter(|&n| grid[n]).count()
+}
+
+fn find_accessible_rolls(grid: &Grid<bool>) -> Vec<Pos> {
+    grid.iter()
+        .filter(|&(pos, &cell)| cell && count_adjacent_papers(grid, pos) < 4)
+        .map(|(pos, _)| pos)
+        .collect()
+}
+
+pub fn part_one(input: &str) -> Option<u64> {
+    let grid = parse_grid(input);
+
+    if grid.is_empty() {
+        return Some(0);
+    }
+
+    let accessible = find_accessible_rolls(&grid);
+    Some(accessible.len() as u64)
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    let mut grid = parse_grid(input);
+
+    if grid.is_empty() {
+        return Some(0);
+    }
+
+    let mut total_removed = 0;
+
+    loop {
+        let accessible = find_accessible_rolls(&grid);
+
+        if accessible.is_empty() {
+            break;
+        }
+
+        // Remove all accessible rolls
+        for &pos in accessible.iter() {
+            grid[pos] = false;
+        }
+
+        total_removed += accessible.len();
+    }
+
+    Some(total_removed as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(13));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(43));
+    }
+}
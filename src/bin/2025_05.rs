@@ -0,0 +1,64 @@
+use advent_of_code::interval_set::IntervalSet;
+
+advent_of_code::solution!(5);
+
+fn parse_fresh_ids(raw: &str) -> Result<IntervalSet, String> {
+    let mut res = IntervalSet::new();
+    for line in raw.lines() {
+        let (start, end) = line
+            .split_once("-")
+            .ok_or_else(|| format!("Error parsing line: {}", line))?;
+        res.insert(parse_number(start)?..=parse_number(end)?);
+    }
+
+    Ok(res)
+}
+
+fn parse_number(raw: &str) -> Result<u64, String> {
+    raw.parse()
+        .map_err(|e| format!("Error parsing number \"{}\": {}", raw, e))
+}
+
+fn split_input(input: &str) -> Result<(&str, &str), String> {
+    input.split_once("\n\n").ok_or_else(|| {
+        "Error parsing input: expected ranges and stock separated by a blank line".into()
+    })
+}
+
+pub fn part_one(input: &str) -> Result<u64, String> {
+    let (ranges, stock) = split_input(input)?;
+
+    let fresh_ids = parse_fresh_ids(ranges)?;
+    let mut count = 0;
+    for line in stock.lines() {
+        if fresh_ids.contains(parse_number(line)?) {
+            count += 1;
+        }
+    }
+
+    Ok(count)
+}
+
+pub fn part_two(input: &str) -> Result<u64, String> {
+    let (ranges, _) = split_input(input)?;
+
+    // Overlapping ranges are merged, so the set knows its total coverage
+    Ok(parse_fresh_ids(ranges)?.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Ok(3));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Ok(14));
+    }
+}
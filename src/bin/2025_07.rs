@@ -0,0 +1,126 @@
+use advent_of_code::{
+    grid::{Grid, Pos},
+    search::memoize,
+};
+
+advent_of_code::solution!(7);
+
+#[derive(Debug)]
+struct Manifold {
+    grid: Grid<char>,
+    src_pos: Pos,
+    width: usize,
+    height: usize,
+}
+
+impl Manifold {
+    fn parse(input: &str) -> Self {
+        let grid = Grid::from_chars(input);
+
+        // Find the starting position 'S'
+        let src_pos = grid.find(&'S').unwrap_or((0, 0));
+
+        Manifold {
+            src_pos,
+            width: grid.width(),
+            height: grid.height(),
+            grid,
+        }
+    }
+
+    fn is_splitter(&self, pos: Pos) -> bool {
+        self.grid.get(pos) == Some(&'^')
+    }
+
+    // Part 1: Count the number of splits
+    fn count_splits(&self) -> u64 {
+        let mut split_count = 0;
+        let mut current_positions = vec![self.src_pos.1]; // Start with column of S
+
+        // Process each row from the start position downward
+        for row in (self.src_pos.0 + 1)..self.height {
+            let mut next_positions = Vec::new();
+
+            for &col in &current_positions {
+                // Check if there's a splitter at this position
+                if self.is_splitter((row, col)) {
+                    split_count += 1;
+                    // Add left and right positions if they're valid
+                    if col > 0 {
+                        next_positions.push(col - 1);
+                    }
+                    if col < self.width - 1 {
+                        next_positions.push(col + 1);
+                    }
+                } else {
+                    // No splitter, beam continues straight down
+                    next_positions.push(col);
+                }
+            }
+
+            // Remove duplicates and sort for consistency
+            next_positions.sort_unstable();
+            next_positions.dedup();
+            current_positions = next_positions;
+        }
+
+        split_count
+    }
+
+    // Part 2: Count the number of unique paths (timelines)
+    fn count_paths(&self) -> u64 {
+        // Use memoization to avoid recalculating the same state
+        memoize(self.src_pos, |recurse, (row, col)| {
+            // Base case: if we're at or past the last row, this is one complete path
+            if row >= self.height {
+                return 1;
+            }
+
+            let mut paths = 0;
+
+            // Check if there's a splitter at the next row at our current column
+            let next_row = row + 1;
+            if next_row < self.height && self.is_splitter((next_row, col)) {
+                // There's a splitter, so we split into left and right paths
+                if col > 0 {
+                    paths += recurse((next_row, col - 1));
+                }
+                if col < self.width - 1 {
+                    paths += recurse((next_row, col + 1));
+                }
+            } else {
+                // No splitter, continue straight down
+                paths += recurse((next_row, col));
+            }
+
+            paths
+        })
+    }
+}
+
+pub fn part_one(input: &str) -> Option<u64> {
+    let manifold = Manifold::parse(input);
+    Some(manifold.count_splits())
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    let manifold = Manifold::parse(input);
+    Some(manifold.count_paths())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(21));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(40));
+    }
+}
@@ -0,0 +1,143 @@
+use advent_of_code::{
+    disjoint_set::DisjointSet,
+    parse::{int, split_n},
+    template::Context,
+};
+
+advent_of_code::solution!(8, context);
+
+#[derive(Clone, Copy)]
+struct JunctionBox {
+    x: usize,
+    y: usize,
+    z: usize,
+}
+
+#[derive(Clone, Copy)]
+struct Edge {
+    jb1: usize, // Index of jb1
+    jb2: usize, // Index of jb2
+    distance: f32,
+}
+
+impl Edge {
+    fn calc_edge(boxes: &[JunctionBox], indexes: (usize, usize)) -> Self {
+        Edge {
+            jb1: indexes.0,
+            jb2: indexes.1,
+            distance: Self::calc_distance(&boxes[indexes.0], &boxes[indexes.1]),
+        }
+    }
+
+    fn calc_distance(jb1: &JunctionBox, jb2: &JunctionBox) -> f32 {
+        let dx = jb1.x as isize - jb2.x as isize;
+        let dy = jb1.y as isize - jb2.y as isize;
+        let dz = jb1.z as isize - jb2.z as isize;
+
+        ((dx * dx + dy * dy + dz * dz) as f32).sqrt()
+    }
+}
+
+impl JunctionBox {
+    fn parse(s: &str) -> Self {
+        let [x, y, z] =
+            split_n(s, ",").unwrap_or_else(|| panic!("Error parsing junction box: {}", s));
+        let coord = |n: &str| int(n).unwrap_or_else(|| panic!("Error parsing coordinate: {}", n));
+
+        Self {
+            x: coord(x),
+            y: coord(y),
+            z: coord(z),
+        }
+    }
+}
+
+pub fn part_one(input: &str, context: &Context) -> Option<u64> {
+    // the example connects the 10 closest pairs, the real input 1000.
+    let take = context.select(10, 1000);
+
+    let mut jbs = Vec::new();
+    for l in input.lines() {
+        jbs.push(JunctionBox::parse(l));
+    }
+
+    let mut edges = Vec::new();
+    for i in 0..jbs.len() {
+        for j in i + 1..jbs.len() {
+            if i == j {
+                continue;
+            }
+            edges.push(Edge::calc_edge(&jbs, (i, j)));
+        }
+    }
+
+    edges.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+
+    let mut set = DisjointSet::new(jbs.len());
+    for edge in edges.iter().take(take) {
+        set.union(edge.jb1, edge.jb2);
+    }
+
+    // Sizes of the circuits, largest first
+    let mut groups: Vec<usize> = set.component_sizes().collect();
+    groups.sort_unstable_by(|a, b| b.cmp(a));
+
+    if groups.len() < 3 {
+        return None;
+    }
+
+    let result = groups[0] as u64 * groups[1] as u64 * groups[2] as u64;
+
+    Some(result)
+}
+
+pub fn part_two(input: &str, _context: &Context) -> Option<u64> {
+    let mut jbs = Vec::new();
+    for l in input.lines() {
+        jbs.push(JunctionBox::parse(l));
+    }
+
+    let mut edges = Vec::new();
+    for i in 0..jbs.len() {
+        for j in i + 1..jbs.len() {
+            if i == j {
+                continue;
+            }
+            edges.push(Edge::calc_edge(&jbs, (i, j)));
+        }
+    }
+
+    edges.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+
+    let mut set = DisjointSet::new(jbs.len());
+
+    for edge in edges {
+        if set.union(edge.jb1, edge.jb2) && set.components() == 1 {
+            // Esta es la última conexión necesaria
+            let x1 = jbs[edge.jb1].x as u64;
+            let x2 = jbs[edge.jb2].x as u64;
+            return Some(x1 * x2);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let context = Context::EXAMPLE;
+        let result = part_one(&context.read(YEAR, DAY), &context);
+        assert_eq!(result, Some(40));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let context = Context::EXAMPLE;
+        let result = part_two(&context.read(YEAR, DAY), &context);
+        assert_eq!(result, Some(25272));
+    }
+}
@@ -0,0 +1,138 @@
+#[cfg(not(target_env = "msvc"))]
+use tikv_jemallocator::Jemalloc;
+
+#[cfg(not(target_env = "msvc"))]
+#[global_allocator]
+static GLOBAL: Jemalloc = Jemalloc;
+advent_of_code::solution!(10);
+
+use advent_of_code::{
+    bitset::BitSet,
+    linear::{Gf2System, LinearSystem},
+};
+
+#[derive(Debug, Clone)]
+struct Machine {
+    goal: BitSet,
+    buttons: Vec<Vec<usize>>,
+    jolt: Vec<usize>,
+}
+
+fn parse_goal(s: &str) -> BitSet {
+    s.trim_matches(|c| c == '[' || c == ']')
+        .chars()
+        .map(|c| c == '#')
+        .collect()
+}
+
+fn parse_button(s: &str) -> Vec<usize> {
+    s.trim_matches(|c| c == '(' || c == ')')
+        .split(',')
+        .map(|n| n.parse().unwrap())
+        .collect()
+}
+
+fn parse_joltage(s: &str) -> Vec<usize> {
+    s.trim_matches(|c| c == '{' || c == '}')
+        .split(',')
+        .map(|n| n.parse().unwrap())
+        .collect()
+}
+
+fn parse_machine(line: &str) -> Machine {
+    let parts: Vec<&str> = line.split_whitespace().collect();
+
+    let goal = parse_goal(parts[0]);
+    let mut buttons = Vec::new();
+
+    let mut i = 1;
+    while i < parts.len() && parts[i].starts_with('(') {
+        buttons.push(parse_button(parts[i]));
+        i += 1;
+    }
+
+    let jolt = parse_joltage(parts[i]);
+
+    Machine {
+        goal,
+        buttons,
+        jolt,
+    }
+}
+
+fn parse_input(input: &str) -> Vec<Machine> {
+    input.lines().map(parse_machine).collect()
+}
+
+pub fn part_one(input: &str) -> Option<u64> {
+    let machines = parse_input(input);
+
+    let sum: usize = machines
+        .iter()
+        .map(|machine| {
+            // one equation per light: the buttons wired to it toggle it into its goal state
+            let coefficients = (0..machine.goal.len())
+                .map(|light| {
+                    machine
+                        .buttons
+                        .iter()
+                        .map(|button| button.contains(&light))
+                        .collect()
+                })
+                .collect();
+
+            Gf2System::new(coefficients, machine.goal.clone())
+                .min_weight_solution()
+                .expect("the lights can be configured")
+                .count_ones()
+        })
+        .sum();
+
+    Some(sum as u64)
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    let machines = parse_input(input);
+
+    let sum: i64 = machines
+        .iter()
+        .map(|machine| {
+            // one equation per counter: the presses of all buttons wired to it add up to its level
+            let coefficients = (0..machine.jolt.len())
+                .map(|counter| {
+                    machine
+                        .buttons
+                        .iter()
+                        .map(|button| i64::from(button.contains(&counter)))
+                        .collect()
+                })
+                .collect();
+            let levels = machine.jolt.iter().map(|&level| level as i64).collect();
+
+            LinearSystem::new(coefficients, levels)
+                .min_sum_solution()
+                .expect("the joltage levels can be reached")
+                .iter()
+                .sum::<i64>()
+        })
+        .sum();
+
+    Some(sum as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(7));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", YEAR, DAY));
+        assert_eq!(result, Some(33));
+    }
+}
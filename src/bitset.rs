@@ -0,0 +1,175 @@
+/// A fixed-size set of bits, packed into 64-bit words.
+///
+/// Useful for states that are toggled a lot, e.g. lights: `^=` flips many bits at once and
+/// `count_ones` counts them, without allocating.
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BitSet {
+    /// Bits past `len` in the last word are always zero.
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitSet {
+    /// Creates a set of `len` bits, all zero.
+    pub fn new(len: usize) -> Self {
+        Self {
+            words: vec![0; len.div_ceil(WORD_BITS)],
+            len,
+        }
+    }
+
+    /// Returns the number of bits, not the number of set bits (see [`BitSet::count_ones`]).
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, index: usize) -> bool {
+        self.check(index);
+        self.words[index / WORD_BITS] & (1 << (index % WORD_BITS)) != 0
+    }
+
+    pub fn set(&mut self, index: usize, value: bool) {
+        self.check(index);
+        let mask = 1 << (index % WORD_BITS);
+        if value {
+            self.words[index / WORD_BITS] |= mask;
+        } else {
+            self.words[index / WORD_BITS] &= !mask;
+        }
+    }
+
+    pub fn flip(&mut self, index: usize) {
+        self.check(index);
+        self.words[index / WORD_BITS] ^= 1 << (index % WORD_BITS);
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Returns `true` if no bit is set.
+    pub fn is_zero(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    /// Returns the indices of the set bits in ascending order.
+    pub fn ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            let mut rest = word;
+            std::iter::from_fn(move || {
+                if rest == 0 {
+                    return None;
+                }
+                let bit = rest.trailing_zeros() as usize;
+                rest &= rest - 1;
+                Some(i * WORD_BITS + bit)
+            })
+        })
+    }
+
+    fn check(&self, index: usize) {
+        assert!(
+            index < self.len,
+            "bit {index} is out of bounds for {} bits.",
+            self.len
+        );
+    }
+}
+
+impl FromIterator<bool> for BitSet {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let mut set = Self::new(0);
+        for value in iter {
+            if set.len.is_multiple_of(WORD_BITS) {
+                set.words.push(0);
+            }
+            set.len += 1;
+            set.set(set.len - 1, value);
+        }
+        set
+    }
+}
+
+/// Implements a bitwise operator word by word, for sets of the same length.
+macro_rules! impl_bit_op {
+    ($($op:ident :: $method:ident, $assign:ident :: $assign_method:ident);*) => {
+        $(
+            impl $assign<&BitSet> for BitSet {
+                fn $assign_method(&mut self, other: &BitSet) {
+                    assert_eq!(self.len, other.len, "expected sets of the same length.");
+                    for (a, &b) in self.words.iter_mut().zip(&other.words) {
+                        a.$assign_method(b);
+                    }
+                }
+            }
+
+            impl $op<&BitSet> for &BitSet {
+                type Output = BitSet;
+
+                fn $method(self, other: &BitSet) -> BitSet {
+                    let mut result = self.clone();
+                    result.$assign_method(other);
+                    result
+                }
+            }
+        )*
+    };
+}
+
+impl_bit_op!(
+    BitXor::bitxor, BitXorAssign::bitxor_assign;
+    BitAnd::bitand, BitAndAssign::bitand_assign;
+    BitOr::bitor, BitOrAssign::bitor_assign
+);
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::BitSet;
+
+    #[test]
+    fn sets_and_flips_bits() {
+        let mut set = BitSet::new(130);
+        set.set(0, true);
+        set.set(64, true);
+        set.flip(129);
+        set.flip(64);
+
+        assert!(set.get(0));
+        assert!(!set.get(64));
+        assert!(set.get(129));
+        assert_eq!(set.count_ones(), 2);
+        assert_eq!(set.ones().collect::<Vec<_>>(), vec![0, 129]);
+        assert!(BitSet::new(3).is_zero());
+    }
+
+    #[test]
+    fn combines_sets() {
+        let a: BitSet = [true, true, false, false].into_iter().collect();
+        let b: BitSet = [false, true, true, false].into_iter().collect();
+
+        assert_eq!((&a ^ &b).ones().collect::<Vec<_>>(), vec![0, 2]);
+        assert_eq!((&a & &b).ones().collect::<Vec<_>>(), vec![1]);
+        assert_eq!((&a | &b).count_ones(), 3);
+
+        let mut c = a.clone();
+        c ^= &a;
+        assert!(c.is_zero());
+        assert_eq!(c.len(), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_out_of_bounds() {
+        BitSet::new(64).get(64);
+    }
+}
@@ -0,0 +1,155 @@
+/// A disjoint-set forest (union-find) over the elements `0..n`.
+///
+/// Uses union by size and iterative path compression, so deep trees can't overflow the stack.
+#[derive(Clone, Debug)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    components: usize,
+}
+
+impl DisjointSet {
+    /// Creates `n` singleton sets.
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            components: n,
+        }
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Returns the representative of the set containing `x`.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        // point every element on the path directly to the root.
+        let mut current = x;
+        while self.parent[current] != root {
+            let next = self.parent[current];
+            self.parent[current] = root;
+            current = next;
+        }
+
+        root
+    }
+
+    /// Merges the sets containing `a` and `b`. Returns `false` if they were in the same set already.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let mut ra = self.find(a);
+        let mut rb = self.find(b);
+
+        if ra == rb {
+            return false;
+        }
+
+        if self.size[ra] < self.size[rb] {
+            std::mem::swap(&mut ra, &mut rb);
+        }
+
+        self.parent[rb] = ra;
+        self.size[ra] += self.size[rb];
+        self.components -= 1;
+        true
+    }
+
+    /// Returns `true` if `a` and `b` are in the same set.
+    pub fn same_set(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Returns the size of the set containing `x`.
+    pub fn size_of(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// Returns the number of disjoint sets.
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    /// Returns the size of every set, in no particular order.
+    pub fn component_sizes(&self) -> impl Iterator<Item = usize> + '_ {
+        self.parent
+            .iter()
+            .enumerate()
+            .filter(|&(x, &parent)| x == parent)
+            .map(|(root, _)| self.size[root])
+    }
+
+    /// Returns the elements of every set, ordered by their smallest element.
+    pub fn groups(&mut self) -> impl Iterator<Item = Vec<usize>> + use<> {
+        let mut index_of_root = vec![usize::MAX; self.len()];
+        let mut groups: Vec<Vec<usize>> = vec![];
+
+        for x in 0..self.len() {
+            let root = self.find(x);
+            if index_of_root[root] == usize::MAX {
+                index_of_root[root] = groups.len();
+                groups.push(vec![]);
+            }
+            groups[index_of_root[root]].push(x);
+        }
+
+        groups.into_iter()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::DisjointSet;
+
+    #[test]
+    fn merges_sets() {
+        let mut set = DisjointSet::new(6);
+
+        assert!(set.union(0, 1));
+        assert!(set.union(1, 2));
+        assert!(set.union(4, 5));
+        assert!(!set.union(0, 2));
+
+        assert!(set.same_set(0, 2));
+        assert!(!set.same_set(2, 3));
+        assert_eq!(set.size_of(1), 3);
+        assert_eq!(set.components(), 3);
+
+        let mut sizes = set.component_sizes().collect::<Vec<_>>();
+        sizes.sort_unstable();
+        assert_eq!(sizes, vec![1, 2, 3]);
+
+        assert_eq!(
+            set.groups().collect::<Vec<_>>(),
+            vec![vec![0, 1, 2], vec![3], vec![4, 5]]
+        );
+    }
+
+    #[test]
+    fn handles_long_chains() {
+        let n = 1_000_000;
+        let mut set = DisjointSet::new(n);
+
+        // link elements one by one, so unions without balancing would build a path of length n.
+        for x in 1..n {
+            set.parent[x] = x - 1;
+        }
+        set.components = 1;
+
+        assert_eq!(set.find(n - 1), 0);
+        assert_eq!(set.parent[n - 1], 0);
+        assert_eq!(set.parent[n / 2], 0);
+    }
+}
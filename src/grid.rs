@@ -0,0 +1,304 @@
+/// A two-dimensional grid, e.g. parsed from a character map of the puzzle input.
+///
+/// Cells are stored in row-major order and addressed by `(row, col)` positions.
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+/// A `(row, col)` position in a grid.
+pub type Pos = (usize, usize);
+
+/// Offsets of the 4 orthogonal neighbours: up, right, down and left.
+pub const DIRECTIONS_4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Offsets of the 8 orthogonal and diagonal neighbours, row by row.
+pub const DIRECTIONS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from cells in row-major order.
+    ///
+    /// # Panics
+    /// If the number of cells doesn't match the size of the grid.
+    pub fn from_cells(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "expected {width}x{height} cells, got {}.",
+            cells.len()
+        );
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses a character map, converting every character to a cell. Blank lines are skipped.
+    ///
+    /// # Panics
+    /// If the lines don't have the same length.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> T) -> Self {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for line in input.lines().filter(|l| !l.trim().is_empty()) {
+            let before = cells.len();
+            cells.extend(line.chars().map(&mut f));
+            let line_width = cells.len() - before;
+
+            match width {
+                None => width = Some(line_width),
+                Some(width) => assert_eq!(
+                    width, line_width,
+                    "expected row {height} to be {width} wide, got {line_width}."
+                ),
+            }
+
+            height += 1;
+        }
+
+        Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, (row, col): Pos) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.0 * self.width + pos.1])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    /// Moves a position by an offset. Returns `None` if the result is outside of the grid.
+    pub fn step(&self, (row, col): Pos, (d_row, d_col): (isize, isize)) -> Option<Pos> {
+        let pos = (
+            row.checked_add_signed(d_row)?,
+            col.checked_add_signed(d_col)?,
+        );
+        self.contains(pos).then_some(pos)
+    }
+
+    /// Returns the orthogonal neighbours of a position that are inside the grid.
+    pub fn neighbours_4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRECTIONS_4.iter().filter_map(move |&d| self.step(pos, d))
+    }
+
+    /// Returns the orthogonal and diagonal neighbours of a position that are inside the grid.
+    pub fn neighbours_8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRECTIONS_8.iter().filter_map(move |&d| self.step(pos, d))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> + '_ {
+        assert!(col < self.width, "column {col} is out of bounds.");
+        self.cells.iter().skip(col).step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        // `chunks` panics for a chunk size of 0, but an empty grid has no rows anyway.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Returns all positions in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// Returns all cells together with their position, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> + '_ {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Returns the position of the first cell that matches the predicate.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter()
+            .find_map(|(pos, cell)| predicate(cell).then_some(pos))
+    }
+
+    /// Maps every cell, keeping the shape of the grid.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Renders the grid with one character per cell, e.g. to print it while debugging.
+    pub fn render(&self, mut f: impl FnMut(&T) -> char) -> String {
+        let mut out = String::with_capacity(self.cells.len() + self.height);
+        for row in self.rows() {
+            out.extend(row.iter().map(&mut f));
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// Returns the position of the first cell with the value, e.g. the start `'S'` of a map.
+    pub fn find(&self, value: &T) -> Option<Pos> {
+        self.position(|cell| cell == value)
+    }
+
+    /// Returns the positions of all cells with the value.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Pos> + 'a {
+        self.iter()
+            .filter_map(move |(pos, cell)| (cell == value).then_some(pos))
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Self::from_cells(width, height, vec![value; width * height])
+    }
+}
+
+impl Grid<char> {
+    /// Parses a character map as is.
+    pub fn from_chars(input: &str) -> Self {
+        Self::parse(input, |c| c)
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("position {pos:?} is out of bounds."))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("position {pos:?} is out of bounds."))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Grid;
+
+    const MAP: &str = "..@\n@S.\n.@@\n";
+
+    #[test]
+    fn parses_character_maps() {
+        let grid = Grid::from_chars(MAP);
+
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid[(1, 1)], 'S');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.row(2), &['.', '@', '@']);
+        assert_eq!(grid.column(0).collect::<String>(), ".@.");
+        assert_eq!(grid.to_string(), MAP);
+    }
+
+    #[test]
+    fn finds_positions() {
+        let grid = Grid::from_chars(MAP);
+
+        assert_eq!(grid.find(&'S'), Some((1, 1)));
+        assert_eq!(grid.find(&'#'), None);
+        assert_eq!(
+            grid.find_all(&'@').collect::<Vec<_>>(),
+            vec![(0, 2), (1, 0), (2, 1), (2, 2)]
+        );
+    }
+
+    #[test]
+    fn iterates_neighbours_in_bounds() {
+        let grid = Grid::new(3, 2, 0);
+
+        assert_eq!(
+            grid.neighbours_4((0, 0)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(
+            grid.neighbours_8((1, 1)).collect::<Vec<_>>(),
+            vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 2)]
+        );
+    }
+
+    #[test]
+    fn maps_and_renders_cells() {
+        let mut grid = Grid::parse(MAP, |c| c == '@');
+        grid[(1, 1)] = true;
+
+        assert_eq!(grid.iter().filter(|(_, cell)| **cell).count(), 5);
+        assert_eq!(
+            grid.render(|&cell| if cell { '#' } else { '.' }),
+            "..#\n##.\n.##\n"
+        );
+        assert_eq!(grid.map(|&cell| u8::from(cell)).row(0), &[0, 0, 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_ragged_maps() {
+        Grid::from_chars("..\n...\n");
+    }
+}
@@ -0,0 +1,201 @@
+/// A set of integers stored as sorted, disjoint inclusive ranges.
+///
+/// Overlapping and adjacent ranges are merged on insertion, so membership is a binary search and
+/// set operations are linear in the number of ranges.
+use std::ops::RangeInclusive;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    /// Sorted by start, neither overlapping nor adjacent.
+    ranges: Vec<(u64, u64)>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds all values of a range. Empty ranges are ignored.
+    pub fn insert(&mut self, range: RangeInclusive<u64>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        // ranges that overlap or touch the new one are merged into it.
+        let first = self
+            .ranges
+            .partition_point(|&(_, e)| e.saturating_add(1) < start);
+        let last = self
+            .ranges
+            .partition_point(|&(s, _)| s <= end.saturating_add(1));
+
+        if first < last {
+            start = start.min(self.ranges[first].0);
+            end = end.max(self.ranges[last - 1].1);
+        }
+
+        self.ranges.splice(first..last, [(start, end)]);
+    }
+
+    pub fn contains(&self, value: u64) -> bool {
+        let index = self.ranges.partition_point(|&(_, e)| e < value);
+        self.ranges.get(index).is_some_and(|&(s, _)| s <= value)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Returns the number of values in the set, saturating at `u64::MAX`.
+    pub fn len(&self) -> u64 {
+        self.ranges.iter().fold(0, |len: u64, &(s, e)| {
+            len.saturating_add((e - s).saturating_add(1))
+        })
+    }
+
+    pub fn min(&self) -> Option<u64> {
+        self.ranges.first().map(|&(s, _)| s)
+    }
+
+    pub fn max(&self) -> Option<u64> {
+        self.ranges.last().map(|&(_, e)| e)
+    }
+
+    /// Returns the merged ranges in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<u64>> + '_ {
+        self.ranges.iter().map(|&(s, e)| s..=e)
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        for range in other.iter() {
+            result.insert(range);
+        }
+        result
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a_start, a_end) = self.ranges[i];
+            let (b_start, b_end) = other.ranges[j];
+
+            let start = a_start.max(b_start);
+            let end = a_end.min(b_end);
+            if start <= end {
+                ranges.push((start, end));
+            }
+
+            if a_end < b_end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        Self { ranges }
+    }
+
+    /// Returns the values of this set that are not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let mut j = 0;
+
+        for &(start, end) in &self.ranges {
+            // skip ranges of `other` that end before this one.
+            while j < other.ranges.len() && other.ranges[j].1 < start {
+                j += 1;
+            }
+
+            // the start of the part of this range that is not cut yet, if any.
+            let mut rest = Some(start);
+            let mut k = j;
+
+            while let Some(rest_start) = rest
+                && k < other.ranges.len()
+                && other.ranges[k].0 <= end
+            {
+                let (cut_start, cut_end) = other.ranges[k];
+                if cut_start > rest_start {
+                    ranges.push((rest_start, cut_start - 1));
+                }
+                rest = (cut_end < end).then(|| cut_end + 1);
+                k += 1;
+            }
+
+            if let Some(rest_start) = rest {
+                ranges.push((rest_start, end));
+            }
+        }
+
+        Self { ranges }
+    }
+}
+
+impl FromIterator<RangeInclusive<u64>> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<u64>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::IntervalSet;
+
+    fn set(ranges: &[(u64, u64)]) -> IntervalSet {
+        ranges.iter().map(|&(s, e)| s..=e).collect()
+    }
+
+    #[test]
+    fn merges_overlapping_and_adjacent_ranges() {
+        let set = set(&[(10, 14), (3, 5), (16, 20), (12, 18), (6, 6)]);
+
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![3..=6, 10..=20]);
+        assert_eq!(set.len(), 15);
+        assert_eq!((set.min(), set.max()), (Some(3), Some(20)));
+    }
+
+    #[test]
+    fn checks_membership() {
+        let set = set(&[(3, 5), (10, 20)]);
+
+        assert!(set.contains(3));
+        assert!(set.contains(15));
+        assert!(!set.contains(6));
+        assert!(!set.contains(21));
+        assert!(!IntervalSet::new().contains(0));
+    }
+
+    #[test]
+    fn combines_sets() {
+        let a = set(&[(0, 10), (20, 30)]);
+        let b = set(&[(5, 25), (40, 50)]);
+
+        assert_eq!(a.union(&b), set(&[(0, 30), (40, 50)]));
+        assert_eq!(a.intersection(&b), set(&[(5, 10), (20, 25)]));
+        assert_eq!(a.difference(&b), set(&[(0, 4), (26, 30)]));
+        assert_eq!(b.difference(&a), set(&[(11, 19), (40, 50)]));
+        assert_eq!(a.difference(&a), IntervalSet::new());
+    }
+
+    #[test]
+    fn handles_bounds() {
+        let full = set(&[(0, u64::MAX)]);
+
+        assert_eq!(full.difference(&set(&[(0, 0)])), set(&[(1, u64::MAX)]));
+        assert_eq!(
+            full.difference(&set(&[(u64::MAX, u64::MAX)])),
+            set(&[(0, u64::MAX - 1)])
+        );
+        assert_eq!(full.difference(&full), IntervalSet::new());
+    }
+}
@@ -1,3 +1,10 @@
+pub mod bitset;
+pub mod disjoint_set;
+pub mod grid;
+pub mod interval_set;
+pub mod linear;
+pub mod parse;
+pub mod search;
 pub mod template;
 
 // Use this file to add helper functions and additional modules.
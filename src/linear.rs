@@ -0,0 +1,575 @@
+/// Small systems of linear equations, e.g. "how often does each button need to be pressed to reach
+/// these counters" or "which buttons toggle these lights".
+///
+/// Systems are brought into reduced row echelon form by Gaussian elimination. Variables without a
+/// pivot are free: they are enumerated, and the pivot variables follow from them.
+use crate::bitset::BitSet;
+
+/// A system of equations over the integers. To stay exact, rows are kept as integer multiples of
+/// their rational form, and free variables are enumerated within their upper bounds.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LinearSystem {
+    /// One row of coefficients per equation.
+    coefficients: Vec<Vec<i64>>,
+    /// The right-hand side of every equation.
+    constants: Vec<i64>,
+}
+
+impl LinearSystem {
+    /// Creates the system `coefficients · x = constants`.
+    ///
+    /// # Panics
+    /// If the rows don't have the same number of variables, or the number of constants doesn't
+    /// match the number of rows.
+    pub fn new(coefficients: Vec<Vec<i64>>, constants: Vec<i64>) -> Self {
+        assert_eq!(
+            coefficients.len(),
+            constants.len(),
+            "expected one constant per equation."
+        );
+        if let Some(first) = coefficients.first() {
+            assert!(
+                coefficients.iter().all(|row| row.len() == first.len()),
+                "expected every equation to have the same number of variables."
+            );
+        }
+
+        Self {
+            coefficients,
+            constants,
+        }
+    }
+
+    /// Returns the number of variables.
+    pub fn variables(&self) -> usize {
+        self.coefficients.first().map_or(0, Vec::len)
+    }
+
+    /// Returns the number of linearly independent equations.
+    pub fn rank(&self) -> usize {
+        let order = (0..self.variables()).collect::<Vec<_>>();
+        self.echelon(&order).pivots.len()
+    }
+
+    /// Returns the upper bound of every variable that follows from the equations, if all
+    /// coefficients and constants are non-negative: a variable can't exceed any constant it
+    /// contributes to. Variables that don't appear in any equation are bounded by 0.
+    pub fn implied_bounds(&self) -> Option<Vec<i64>> {
+        if self.constants.iter().any(|&c| c < 0) {
+            return None;
+        }
+
+        (0..self.variables())
+            .map(|var| {
+                let mut bound = 0;
+                let mut seen = false;
+
+                for (row, &constant) in self.coefficients.iter().zip(&self.constants) {
+                    match row[var] {
+                        0 => {}
+                        a if a < 0 => return None,
+                        a => {
+                            bound = if seen {
+                                bound.min(constant / a)
+                            } else {
+                                constant / a
+                            };
+                            seen = true;
+                        }
+                    }
+                }
+
+                Some(bound)
+            })
+            .collect()
+    }
+
+    /// Finds the non-negative integer solution with the smallest sum of variables, using
+    /// [`LinearSystem::implied_bounds`]. Returns `None` if there is no such solution.
+    ///
+    /// # Panics
+    /// If a variable has no implied bound, see [`LinearSystem::min_sum_solution_bounded`].
+    pub fn min_sum_solution(&self) -> Option<Vec<i64>> {
+        let bounds = self
+            .implied_bounds()
+            .expect("expected non-negative coefficients and constants to bound the variables.");
+        self.min_sum_solution_bounded(&bounds)
+    }
+
+    /// Finds the integer solution with `0 <= x[i] <= upper_bounds[i]` and the smallest sum of
+    /// variables. Returns `None` if there is no such solution.
+    ///
+    /// The runtime grows with the product of the bounds of the free variables, so this is meant
+    /// for systems that are almost determined.
+    pub fn min_sum_solution_bounded(&self, upper_bounds: &[i64]) -> Option<Vec<i64>> {
+        assert_eq!(
+            upper_bounds.len(),
+            self.variables(),
+            "expected one upper bound per variable."
+        );
+
+        // variables with large bounds make expensive free variables, so pick them as pivots first.
+        let mut order = (0..self.variables()).collect::<Vec<_>>();
+        order.sort_by_key(|&var| std::cmp::Reverse(upper_bounds[var]));
+
+        let echelon = self.echelon(&order);
+        if echelon.is_inconsistent {
+            return None;
+        }
+
+        Search::new(&echelon, upper_bounds).run()
+    }
+
+    /// Eliminates the variables in the given order, which decides the pivot variables.
+    fn echelon(&self, order: &[usize]) -> Echelon {
+        let variables = self.variables();
+        // every row holds its coefficients followed by its constant.
+        let mut rows: Vec<Vec<i128>> = self
+            .coefficients
+            .iter()
+            .zip(&self.constants)
+            .map(|(row, &c)| row.iter().chain([&c]).map(|&x| i128::from(x)).collect())
+            .collect();
+
+        let mut pivots = vec![];
+
+        for &var in order {
+            let next = pivots.len();
+            let Some(pivot_row) = (next..rows.len()).find(|&r| rows[r][var] != 0) else {
+                continue;
+            };
+            rows.swap(next, pivot_row);
+
+            let pivot = rows[next].clone();
+            for (r, row) in rows.iter_mut().enumerate() {
+                let factor = row[var];
+                if r == next || factor == 0 {
+                    continue;
+                }
+
+                // subtract a multiple of the pivot row without leaving the integers.
+                for (x, &p) in row.iter_mut().zip(&pivot) {
+                    *x = *x * pivot[var] - p * factor;
+                }
+                normalize(row);
+            }
+
+            pivots.push(var);
+        }
+
+        for (r, row) in rows.iter_mut().enumerate().take(pivots.len()) {
+            // keep pivots positive, so the sign of a pivot variable follows its right-hand side.
+            if row[pivots[r]] < 0 {
+                row.iter_mut().for_each(|x| *x = -*x);
+            }
+        }
+
+        // rows without pivots have zero coefficients, so their constant must be zero as well.
+        let is_inconsistent = rows[pivots.len()..].iter().any(|row| row[variables] != 0);
+
+        let free = (0..variables).filter(|var| !pivots.contains(var)).collect();
+        rows.truncate(pivots.len());
+
+        Echelon {
+            rows,
+            pivots,
+            free,
+            is_inconsistent,
+        }
+    }
+}
+
+/// The reduced row echelon form of a system, with a row per pivot variable.
+struct Echelon {
+    rows: Vec<Vec<i128>>,
+    pivots: Vec<usize>,
+    free: Vec<usize>,
+    is_inconsistent: bool,
+}
+
+/// Enumerates the values of the free variables, keeping the best solution found.
+struct Search<'a> {
+    echelon: &'a Echelon,
+    upper_bounds: &'a [i64],
+    /// The sum of all variables, times `scale` to stay in the integers, is `offset` plus the
+    /// free variables times their `weights`.
+    scale: i128,
+    offset: i128,
+    weights: Vec<i128>,
+    values: Vec<i64>,
+    best: Option<(i64, Vec<i64>)>,
+}
+
+impl<'a> Search<'a> {
+    fn new(echelon: &'a Echelon, upper_bounds: &'a [i64]) -> Self {
+        let variables = upper_bounds.len();
+        let pivot_rows = || echelon.rows.iter().zip(&echelon.pivots);
+
+        // every pivot variable is `(constant - free terms) / pivot`.
+        let scale = pivot_rows().fold(1, |scale, (row, &pivot)| lcm(scale, row[pivot]));
+        let mut offset = 0;
+        let mut weights = vec![scale; variables];
+
+        for (row, &pivot) in pivot_rows() {
+            let factor = scale / row[pivot];
+            offset += row[variables] * factor;
+            for &free in &echelon.free {
+                weights[free] -= row[free] * factor;
+            }
+        }
+
+        Self {
+            echelon,
+            upper_bounds,
+            scale,
+            offset,
+            weights,
+            values: vec![0; variables],
+            best: None,
+        }
+    }
+
+    fn run(mut self) -> Option<Vec<i64>> {
+        self.enumerate(0, self.offset);
+        self.best.map(|(_, values)| values)
+    }
+
+    /// Assigns the free variable at `index` and all after it. `partial` is the scaled sum of all
+    /// variables with the remaining free variables at zero.
+    fn enumerate(&mut self, index: usize, partial: i128) {
+        let Some(&var) = self.echelon.free.get(index) else {
+            self.solve_pivots();
+            return;
+        };
+        let Some((low, high)) = self.range(index, partial) else {
+            return;
+        };
+
+        // try the values that make the sum smaller first, to find good solutions early.
+        let mut values = low..=high;
+        while let Some(value) = if self.weights[var] < 0 {
+            values.next_back()
+        } else {
+            values.next()
+        } {
+            self.values[var] = value;
+            self.enumerate(index + 1, partial + self.weights[var] * i128::from(value));
+        }
+        self.values[var] = 0;
+    }
+
+    /// Returns the values the free variable at `index` can take, so that every pivot variable can
+    /// still stay within its bounds and the sum can still beat the best solution.
+    fn range(&self, index: usize, partial: i128) -> Option<(i64, i64)> {
+        let variables = self.values.len();
+        let (assigned, open) = self.echelon.free.split_at(index);
+        let (&var, rest) = open.split_first()?;
+        let (mut low, mut high) = (0, i128::from(self.upper_bounds[var]));
+
+        // the smallest and largest value of a linear term over the remaining free variables.
+        let rest_range = |coefficients: &[i128]| {
+            rest.iter().fold((0, 0), |(low, high), &free| {
+                let term = coefficients[free] * i128::from(self.upper_bounds[free]);
+                (low + term.min(0), high + term.max(0))
+            })
+        };
+        // restricts `var` to `at_least <= coefficient * var <= at_most`.
+        let mut restrict = |coefficient: i128, at_least: i128, at_most: i128| match coefficient {
+            0 => at_least <= 0 && 0 <= at_most,
+            c if c > 0 => {
+                low = low.max(div_ceil(at_least, c));
+                high = high.min(at_most.div_euclid(c));
+                true
+            }
+            c => {
+                low = low.max(div_ceil(-at_most, -c));
+                high = high.min((-at_least).div_euclid(-c));
+                true
+            }
+        };
+
+        for (row, &pivot) in self.echelon.rows.iter().zip(&self.echelon.pivots) {
+            let fixed: i128 = assigned
+                .iter()
+                .map(|&free| row[free] * i128::from(self.values[free]))
+                .sum();
+            let (rest_low, rest_high) = rest_range(row);
+
+            // 0 <= constant - fixed - coefficient * var - rest <= pivot * bound of the pivot variable
+            let numerator = row[variables] - fixed;
+            let pivot_range = row[pivot] * i128::from(self.upper_bounds[pivot]);
+            if !restrict(
+                row[var],
+                numerator - rest_high - pivot_range,
+                numerator - rest_low,
+            ) {
+                return None;
+            }
+        }
+
+        if let Some((best, _)) = &self.best {
+            // partial + weight * var + rest < best * scale
+            let (rest_low, _) = rest_range(&self.weights);
+            let at_most = (i128::from(*best) * self.scale - 1) - partial - rest_low;
+            if !restrict(self.weights[var], i128::MIN / 2, at_most) {
+                return None;
+            }
+        }
+
+        (low <= high).then_some((low as i64, high as i64))
+    }
+
+    /// Derives the pivot variables from the free ones, if they are integers within their bounds.
+    fn solve_pivots(&mut self) {
+        let variables = self.values.len();
+
+        for (row, &var) in self.echelon.rows.iter().zip(&self.echelon.pivots) {
+            let rest: i128 = self
+                .echelon
+                .free
+                .iter()
+                .map(|&free| row[free] * i128::from(self.values[free]))
+                .sum();
+            let numerator = row[variables] - rest;
+
+            if numerator % row[var] != 0 {
+                return;
+            }
+            let Ok(value) = i64::try_from(numerator / row[var]) else {
+                return;
+            };
+            if !(0..=self.upper_bounds[var]).contains(&value) {
+                return;
+            }
+
+            self.values[var] = value;
+        }
+
+        let sum = self.values.iter().sum();
+        if self.best.as_ref().is_none_or(|(best, _)| sum < *best) {
+            self.best = Some((sum, self.values.clone()));
+        }
+    }
+}
+
+/// Divides a row by the greatest common divisor of its entries, to keep the numbers small.
+fn normalize(row: &mut [i128]) {
+    let divisor = row.iter().fold(0, |acc, &x| gcd(acc, x.abs()));
+    if divisor > 1 {
+        row.iter_mut().for_each(|x| *x /= divisor);
+    }
+}
+
+fn div_ceil(a: i128, b: i128) -> i128 {
+    -(-a).div_euclid(b)
+}
+
+fn lcm(a: i128, b: i128) -> i128 {
+    a / gcd(a, b) * b
+}
+
+fn gcd(a: i128, b: i128) -> i128 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A system of equations over GF(2), where adding is XOR, e.g. "which buttons need to be pressed
+/// to toggle these lights". Pressing a button twice cancels out, so every variable is a bit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Gf2System {
+    /// One set of variables per equation, whose XOR is the equation's constant.
+    coefficients: Vec<BitSet>,
+    constants: BitSet,
+}
+
+impl Gf2System {
+    /// Creates the system `coefficients · x = constants`, where `constants` holds a bit per row.
+    ///
+    /// # Panics
+    /// If the rows don't have the same number of variables, or the number of constants doesn't
+    /// match the number of rows.
+    pub fn new(coefficients: Vec<BitSet>, constants: BitSet) -> Self {
+        assert_eq!(
+            coefficients.len(),
+            constants.len(),
+            "expected one constant per equation."
+        );
+        if let Some(first) = coefficients.first() {
+            assert!(
+                coefficients.iter().all(|row| row.len() == first.len()),
+                "expected every equation to have the same number of variables."
+            );
+        }
+
+        Self {
+            coefficients,
+            constants,
+        }
+    }
+
+    /// Returns the number of variables.
+    pub fn variables(&self) -> usize {
+        self.coefficients.first().map_or(0, BitSet::len)
+    }
+
+    /// Finds the solution with the fewest set variables. Returns `None` if there is no solution.
+    ///
+    /// Every combination of free variables is tried, so the runtime doubles with each of them.
+    ///
+    /// # Panics
+    /// If there are 64 or more free variables.
+    pub fn min_weight_solution(&self) -> Option<BitSet> {
+        let variables = self.variables();
+        // every row holds its coefficients and its constant.
+        let mut rows = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .map(|(r, row)| (row.clone(), self.constants.get(r)))
+            .collect::<Vec<_>>();
+        let mut pivots = vec![];
+
+        for var in 0..variables {
+            let next = pivots.len();
+            let Some(pivot_row) = (next..rows.len()).find(|&r| rows[r].0.get(var)) else {
+                continue;
+            };
+            rows.swap(next, pivot_row);
+
+            let (pivot, constant) = rows[next].clone();
+            for (r, (row, row_constant)) in rows.iter_mut().enumerate() {
+                if r != next && row.get(var) {
+                    *row ^= &pivot;
+                    *row_constant ^= constant;
+                }
+            }
+
+            pivots.push(var);
+        }
+
+        // rows without pivots are empty, so their constant must be zero as well.
+        if rows[pivots.len()..].iter().any(|&(_, constant)| constant) {
+            return None;
+        }
+
+        let free = (0..variables)
+            .filter(|var| !pivots.contains(var))
+            .collect::<Vec<_>>();
+        assert!(free.len() < 64, "expected fewer than 64 free variables.");
+
+        // the solution with all free variables unset, and the change of setting each of them.
+        let mut base = BitSet::new(variables);
+        for ((_, constant), &var) in rows.iter().zip(&pivots) {
+            base.set(var, *constant);
+        }
+        let toggles = free
+            .iter()
+            .map(|&free_var| {
+                let mut toggle = BitSet::new(variables);
+                toggle.set(free_var, true);
+                for ((row, _), &var) in rows.iter().zip(&pivots) {
+                    toggle.set(var, row.get(free_var));
+                }
+                toggle
+            })
+            .collect::<Vec<_>>();
+
+        // walk all combinations in Gray code order, so each step toggles a single free variable.
+        let mut solution = base.clone();
+        let mut best = (solution.count_ones(), 0_u64);
+        for step in 1..1_u64 << free.len() {
+            solution ^= &toggles[step.trailing_zeros() as usize];
+            let weight = solution.count_ones();
+            if weight < best.0 {
+                best = (weight, step ^ (step >> 1));
+            }
+        }
+
+        let (_, combination) = best;
+        for (i, toggle) in toggles.iter().enumerate() {
+            if combination & (1 << i) != 0 {
+                base ^= toggle;
+            }
+        }
+
+        Some(base)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Gf2System, LinearSystem};
+    use crate::bitset::BitSet;
+
+    #[test]
+    fn solves_determined_systems() {
+        // x + y = 10, x - y = 2
+        let system = LinearSystem::new(vec![vec![1, 1], vec![1, -1]], vec![10, 2]);
+
+        assert_eq!(system.rank(), 2);
+        assert_eq!(system.implied_bounds(), None);
+        assert_eq!(system.min_sum_solution_bounded(&[10, 10]), Some(vec![6, 4]));
+        assert_eq!(system.min_sum_solution_bounded(&[5, 10]), None);
+    }
+
+    #[test]
+    fn minimizes_free_variables() {
+        // buttons (3) (1,3) (2) (2,3) (0,2) (0,1) reaching the counters {3,5,4,7}.
+        let buttons: [&[usize]; 6] = [&[3], &[1, 3], &[2], &[2, 3], &[0, 2], &[0, 1]];
+        let coefficients = (0..4)
+            .map(|counter| {
+                buttons
+                    .iter()
+                    .map(|b| i64::from(b.contains(&counter)))
+                    .collect()
+            })
+            .collect();
+        let system = LinearSystem::new(coefficients, vec![3, 5, 4, 7]);
+
+        assert_eq!(system.rank(), 4);
+        assert_eq!(system.implied_bounds(), Some(vec![7, 5, 4, 4, 3, 3]));
+
+        let solution = system.min_sum_solution().unwrap();
+        assert_eq!(solution.iter().sum::<i64>(), 10);
+    }
+
+    #[test]
+    fn rejects_inconsistent_systems() {
+        // x + y = 1, 2x + 2y = 3
+        let system = LinearSystem::new(vec![vec![1, 1], vec![2, 2]], vec![1, 3]);
+        assert_eq!(system.min_sum_solution(), None);
+
+        // 2x = 3 has no integer solution.
+        let system = LinearSystem::new(vec![vec![2]], vec![3]);
+        assert_eq!(system.min_sum_solution(), None);
+    }
+
+    #[test]
+    fn finds_lightest_gf2_solutions() {
+        // buttons (3) (1,3) (2) (2,3) (0,2) (0,1) toggling the lights [.##.].
+        let buttons: [&[usize]; 6] = [&[3], &[1, 3], &[2], &[2, 3], &[0, 2], &[0, 1]];
+        let coefficients = (0..4)
+            .map(|light| buttons.iter().map(|b| b.contains(&light)).collect())
+            .collect();
+        let goal = [false, true, true, false].into_iter().collect();
+        let system = Gf2System::new(coefficients, goal);
+
+        let solution = system.min_weight_solution().unwrap();
+        assert_eq!(solution.count_ones(), 2);
+        let mut lights = [false; 4];
+        for button in solution.ones() {
+            buttons[button]
+                .iter()
+                .for_each(|&light| lights[light] ^= true);
+        }
+        assert_eq!(lights, [false, true, true, false]);
+
+        // x0 = 1, x0 = 0
+        let one: BitSet = [true].into_iter().collect();
+        let system = Gf2System::new(vec![one.clone(), one], [true, false].into_iter().collect());
+        assert_eq!(system.min_weight_solution(), None);
+    }
+}
@@ -1,15 +1,20 @@
 use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::registry::Registry;
 use args::{AppArguments, parse};
 
 #[cfg(feature = "today")]
 use advent_of_code::template::Day;
+use advent_of_code::template::Year;
 use std::env;
 #[cfg(feature = "today")]
 use std::process;
 
 mod args {
-    use advent_of_code::template::Day;
-    use std::process;
+    use advent_of_code::template::{Day, Limits, Statistic, Year};
+    use std::{error::Error, process, time::Duration};
+
+    /// Slowdown in percent that `time --compare` tolerates by default.
+    const DEFAULT_REGRESSION_THRESHOLD: f64 = 10.0;
 
     pub enum AppArguments {
         Download {
@@ -22,58 +27,98 @@ mod args {
             day: Day,
             download: bool,
             overwrite: bool,
+            template: Option<String>,
         },
         Solve {
             day: Day,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            check: bool,
+            limits: Limits,
         },
         All {
             release: bool,
+            check: bool,
+            subprocess: bool,
+            jobs: usize,
+            limits: Limits,
         },
         Time {
             all: bool,
             day: Option<Day>,
             store: bool,
+            statistic: Statistic,
+            compare_threshold: Option<f64>,
+            history: bool,
+            subprocess: bool,
+            limits: Limits,
         },
         #[cfg(feature = "today")]
         Today,
     }
 
-    pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
+    /// Parses the command and the `--year` it applies to, if passed.
+    pub fn parse() -> Result<(Option<Year>, AppArguments), Box<dyn Error>> {
         let mut args = pico_args::Arguments::from_env();
+        let subcommand = args.subcommand()?;
+
+        let year: Option<Year> = args.opt_value_from_str("--year")?;
+        let event = year.unwrap_or_else(Year::current);
 
-        let app_args = match args.subcommand()?.as_deref() {
+        let app_args = match subcommand.as_deref() {
             Some("all") => AppArguments::All {
                 release: args.contains("--release"),
+                check: args.contains("--check"),
+                subprocess: args.contains("--subprocess"),
+                jobs: args.opt_value_from_str("--jobs")?.unwrap_or(1),
+                limits: parse_limits(&mut args)?,
             },
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let statistic = args.opt_value_from_str("--stat")?.unwrap_or_default();
+                let history = args.contains("--history");
+                let subprocess = args.contains("--subprocess");
+                let compare = args.contains("--compare");
+                let threshold: Option<f64> = args.opt_value_from_str("--threshold")?;
+                let compare_threshold = (compare || threshold.is_some())
+                    .then(|| threshold.unwrap_or(DEFAULT_REGRESSION_THRESHOLD));
+                let limits = parse_limits(&mut args)?;
 
                 AppArguments::Time {
                     all,
-                    day: args.opt_free_from_str()?,
+                    day: args
+                        .opt_free_from_str::<String>()?
+                        .map(|day| event.parse_day(&day))
+                        .transpose()?,
                     store,
+                    statistic,
+                    compare_threshold,
+                    history,
+                    subprocess,
+                    limits,
                 }
             }
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                day: parse_day(&mut args, event)?,
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                day: parse_day(&mut args, event)?,
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                day: parse_day(&mut args, event)?,
                 download: args.contains("--download"),
                 overwrite: args.contains("-f"),
+                template: args.opt_value_from_str("--template")?,
             },
             Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
+                day: parse_day(&mut args, event)?,
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("-s")?,
                 dhat: args.contains("--dhat"),
+                check: args.contains("--check"),
+                limits: parse_limits(&mut args)?,
             },
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
@@ -92,10 +137,34 @@ mod args {
             eprintln!("Warning: unknown argument(s): {remaining:?}.");
         }
 
-        Ok(app_args)
+        Ok((year, app_args))
+    }
+
+    fn parse_day(args: &mut pico_args::Arguments, year: Year) -> Result<Day, Box<dyn Error>> {
+        Ok(year.parse_day(&args.free_from_str::<String>()?)?)
+    }
+
+    /// Parses `--timeout <seconds>` and `--max-rss <megabytes>`.
+    fn parse_limits(args: &mut pico_args::Arguments) -> Result<Limits, pico_args::Error> {
+        let timeout: Option<f64> = args.opt_value_from_str("--timeout")?;
+        Ok(Limits {
+            timeout: timeout.map(Duration::from_secs_f64),
+            max_rss_mb: args.opt_value_from_str("--max-rss")?,
+        })
     }
 }
 
+/// Every scaffolded solution that could be linked into this binary, see `build.rs`.
+mod solutions {
+    include!(concat!(env!("OUT_DIR"), "/registry.rs"));
+}
+
+/// Returns the in-process registry, unless running every day as a child process was requested.
+/// Only solutions of the configured year are linked, so other years always run as child processes.
+fn linked_solutions(subprocess: bool) -> Option<Registry> {
+    (!subprocess && Year::current() == Year::CONFIGURED).then(solutions::registry)
+}
+
 fn main() {
     let env = include_str!("../.env");
     for line in env.lines() {
@@ -111,44 +180,94 @@ fn main() {
             eprintln!("Error: {err}");
             std::process::exit(1);
         }
-        Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold {
-                day,
-                download,
-                overwrite,
-            } => {
-                scaffold::handle(day, overwrite);
-                if download {
-                    download::handle(day);
+        Ok((year, args)) => {
+            if let Some(year) = year {
+                // child processes, e.g. `cargo run`, inherit the year.
+                unsafe {
+                    env::set_var("AOC_YEAR", year.to_string());
                 }
             }
-            AppArguments::Solve {
-                day,
-                release,
-                dhat,
-                submit,
-            } => solve::handle(day, release, dhat, submit),
-            #[cfg(feature = "today")]
-            AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
+
+            match args {
+                AppArguments::All {
+                    release,
+                    check,
+                    subprocess,
+                    jobs,
+                    limits,
+                } => all::handle(
+                    release,
+                    check,
+                    linked_solutions(subprocess).as_ref(),
+                    jobs,
+                    limits,
+                ),
+                AppArguments::Time {
+                    day,
+                    all,
+                    store,
+                    statistic,
+                    compare_threshold,
+                    history,
+                    subprocess,
+                    limits,
+                } => match (history, day) {
+                    (true, Some(day)) => time::handle_history(day, statistic),
+                    (true, None) => {
+                        eprintln!("`--history` requires a day, e.g. `cargo time 8 --history`.");
+                        std::process::exit(1);
                     }
-                    None => {
-                        eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
-                        );
-                        process::exit(1)
+                    (false, _) => time::handle(
+                        day,
+                        all,
+                        store,
+                        statistic,
+                        compare_threshold,
+                        linked_solutions(subprocess).as_ref(),
+                        limits,
+                    ),
+                },
+                AppArguments::Download { day } => download::handle(day),
+                AppArguments::Read { day } => read::handle(day),
+                AppArguments::Scaffold {
+                    day,
+                    download,
+                    overwrite,
+                    template,
+                } => {
+                    // download first, so the examples can be extracted from the puzzle.
+                    if download {
+                        download::handle(day);
                     }
-                };
+                    scaffold::handle(day, overwrite, template.as_deref());
+                }
+                AppArguments::Solve {
+                    day,
+                    release,
+                    dhat,
+                    submit,
+                    check,
+                    limits,
+                } => solve::handle(day, release, dhat, submit, check, limits),
+                #[cfg(feature = "today")]
+                AppArguments::Today => {
+                    match Day::today() {
+                        Some(day) => {
+                            download::handle(day);
+                            scaffold::handle(day, false, None);
+                            read::handle(day)
+                        }
+                        None => {
+                            eprintln!(
+                                "`today` command can only be run between the 1st and \
+                            the {}th of december. Please use `scaffold` with a specific day.",
+                                Year::current().last_day()
+                            );
+                            process::exit(1)
+                        }
+                    };
+                }
             }
-        },
+        }
     };
 }
@@ -0,0 +1,220 @@
+/// Fast parsing helpers for puzzle inputs.
+///
+/// Integers are read directly from bytes, without the UTF-8 and error handling overhead of
+/// `str::parse`, and none of the helpers allocate unless they return a collection.
+use std::marker::PhantomData;
+
+use crate::grid::Grid;
+
+/// Integer types that can be parsed from ASCII digits.
+pub trait Int: Copy {
+    const SIGNED: bool;
+
+    /// Parses an optional sign followed by at least one digit. Returns `None` on overflow.
+    fn from_ascii(bytes: &[u8]) -> Option<Self>;
+}
+
+/// Implements [`Int`] by accumulating digits with checked arithmetic.
+macro_rules! impl_int {
+    ($signed:expr => $($t:ty),*) => {
+        $(
+            impl Int for $t {
+                const SIGNED: bool = $signed;
+
+                fn from_ascii(bytes: &[u8]) -> Option<Self> {
+                    let (negative, digits) = match bytes {
+                        [b'-', rest @ ..] if Self::SIGNED => (true, rest),
+                        [b'+', rest @ ..] => (false, rest),
+                        _ => (false, bytes),
+                    };
+
+                    if digits.is_empty() {
+                        return None;
+                    }
+
+                    let mut value: Self = 0;
+                    for &b in digits {
+                        if !b.is_ascii_digit() {
+                            return None;
+                        }
+                        let digit = (b - b'0') as Self;
+                        // accumulate negative numbers downwards, so `MIN` doesn't overflow.
+                        value = value.checked_mul(10)?;
+                        value = if negative {
+                            value.checked_sub(digit)?
+                        } else {
+                            value.checked_add(digit)?
+                        };
+                    }
+
+                    Some(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_int!(false => u8, u16, u32, u64, u128, usize);
+impl_int!(true => i8, i16, i32, i64, i128, isize);
+
+/// Parses a whole string as an integer, e.g. `int::<u64>("42")`. Surrounding whitespace is ignored.
+pub fn int<T: Int>(s: &str) -> Option<T> {
+    T::from_ascii(s.trim().as_bytes())
+}
+
+/// Returns an iterator over all integers in a text, skipping everything in between.
+///
+/// For signed types, a `-` directly before a number makes it negative, e.g.
+/// `ints::<i64>("x=10, y=-3")` yields `10` and `-3`. Unsigned types ignore signs.
+///
+/// # Panics
+/// If a number doesn't fit into the type.
+pub fn ints<T: Int>(s: &str) -> Ints<'_, T> {
+    Ints {
+        bytes: s.as_bytes(),
+        position: 0,
+        marker: PhantomData,
+    }
+}
+
+pub struct Ints<'a, T> {
+    bytes: &'a [u8],
+    position: usize,
+    marker: PhantomData<T>,
+}
+
+impl<T: Int> Iterator for Ints<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let bytes = self.bytes;
+        let offset = bytes[self.position..].iter().position(u8::is_ascii_digit)?;
+        let digits_start = self.position + offset;
+
+        let start = if T::SIGNED && digits_start > 0 && bytes[digits_start - 1] == b'-' {
+            digits_start - 1
+        } else {
+            digits_start
+        };
+
+        let end = bytes[digits_start..]
+            .iter()
+            .position(|b| !b.is_ascii_digit())
+            .map_or(bytes.len(), |len| digits_start + len);
+
+        self.position = end;
+
+        let number = &bytes[start..end];
+        Some(T::from_ascii(number).unwrap_or_else(|| {
+            panic!(
+                "\"{}\" does not fit into the integer type.",
+                String::from_utf8_lossy(number)
+            )
+        }))
+    }
+}
+
+/// Splits a string into exactly `N` parts at the first `N - 1` occurrences of the delimiter.
+/// The last part holds the rest of the string. Returns `None` if there are fewer parts.
+///
+/// E.g. `let [x, y, z] = split_n(line, ",")?;`
+pub fn split_n<'a, const N: usize>(s: &'a str, delimiter: &str) -> Option<[&'a str; N]> {
+    let mut parts = s.splitn(N, delimiter);
+    let result = [(); N].map(|()| parts.next());
+
+    if result.iter().any(Option::is_none) {
+        return None;
+    }
+
+    Some(result.map(Option::unwrap))
+}
+
+/// Returns the values of the ASCII digits of a line, e.g. `[1, 2, 3]` for `"123"`.
+///
+/// # Panics
+/// If the line contains anything but digits.
+pub fn digits(line: &str) -> impl Iterator<Item = u8> + '_ {
+    line.bytes().map(|b| {
+        assert!(b.is_ascii_digit(), "expected a digit, got '{}'.", b as char);
+        b - b'0'
+    })
+}
+
+/// Reads a block of digits into one row of values per line. Blank lines are skipped.
+pub fn digit_rows(input: &str) -> Vec<Vec<u8>> {
+    input
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| digits(line).collect())
+        .collect()
+}
+
+/// Reads a block of digits into a grid of values.
+///
+/// # Panics
+/// If the block contains anything but digits, or if the lines don't have the same length.
+pub fn digit_grid(input: &str) -> Grid<u8> {
+    Grid::parse(input, |c| {
+        c.to_digit(10)
+            .unwrap_or_else(|| panic!("expected a digit, got '{c}'.")) as u8
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{digit_grid, digit_rows, int, ints, split_n};
+
+    #[test]
+    fn parses_integers() {
+        assert_eq!(int::<u64>("18446744073709551615"), Some(u64::MAX));
+        assert_eq!(int::<u64>("18446744073709551616"), None);
+        assert_eq!(int::<u64>("-1"), None);
+        assert_eq!(int::<i8>("-128"), Some(i8::MIN));
+        assert_eq!(int::<i8>("+127\n"), Some(127));
+        assert_eq!(int::<i32>("-"), None);
+        assert_eq!(int::<usize>("12a"), None);
+    }
+
+    #[test]
+    fn extracts_integers_from_text() {
+        let text = "p=10,-3 v=-1,+42 at 2025-12-08";
+
+        assert_eq!(
+            ints::<i64>(text).collect::<Vec<_>>(),
+            vec![10, -3, -1, 42, 2025, -12, -8]
+        );
+        assert_eq!(
+            ints::<u32>(text).collect::<Vec<_>>(),
+            vec![10, 3, 1, 42, 2025, 12, 8]
+        );
+        assert_eq!(ints::<u8>("no numbers").next(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_overflowing_integers() {
+        ints::<u8>("256").for_each(drop);
+    }
+
+    #[test]
+    fn splits_into_fixed_parts() {
+        assert_eq!(split_n("162,817,812", ","), Some(["162", "817", "812"]));
+        assert_eq!(split_n("a: b: c", ": "), Some(["a", "b: c"]));
+        assert_eq!(split_n::<3>("1,2", ","), None);
+    }
+
+    #[test]
+    fn reads_digits() {
+        assert_eq!(
+            digit_rows("123\n\n045\n"),
+            vec![vec![1, 2, 3], vec![0, 4, 5]]
+        );
+
+        let grid = digit_grid("12\n34\n");
+        assert_eq!(grid[(1, 0)], 3);
+        assert_eq!(grid.width(), 2);
+    }
+}
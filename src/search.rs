@@ -0,0 +1,317 @@
+/// Generic searches over implicit graphs.
+///
+/// Puzzles describe their graphs by a start state and the transitions of a state, so every search
+/// takes a `neighbours` closure instead of an adjacency structure. States are cloned into the
+/// visited set, so keep them small and cheap to hash.
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque, hash_map::Entry},
+    hash::Hash,
+    ops::Add,
+};
+
+/// A path found by a search, from the start to the goal state, and its total cost.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub states: Vec<S>,
+    pub cost: C,
+}
+
+/// Finds a path with the fewest steps from `start` to a state that satisfies `is_goal`.
+pub fn bfs<S, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut nodes = Nodes::new(start.clone(), 0_usize);
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(index) = queue.pop_front() {
+        let state = nodes.state(index).clone();
+        if is_goal(&state) {
+            return Some(nodes.path(index));
+        }
+
+        let steps = nodes.cost(index) + 1;
+        for next in neighbours(&state) {
+            if let Some(next_index) = nodes.visit(next, index, steps, |_, _| false) {
+                queue.push_back(next_index);
+            }
+        }
+    }
+
+    None
+}
+
+/// Returns the fewest steps from `start` to every reachable state.
+pub fn bfs_distances<S, I>(start: S, mut neighbours: impl FnMut(&S) -> I) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((state, steps)) = queue.pop_front() {
+        for next in neighbours(&state) {
+            if let Entry::Vacant(entry) = distances.entry(next.clone()) {
+                entry.insert(steps + 1);
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+
+    distances
+}
+
+/// Finds a cheapest path from `start` to a state that satisfies `is_goal`.
+/// `neighbours` returns the next states together with the (non-negative) cost of the step.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, neighbours, |_| C::default(), is_goal)
+}
+
+/// Finds a cheapest path like [`dijkstra`], exploring states with a low `cost + heuristic` first.
+///
+/// The heuristic estimates the remaining cost to a goal. It must never overestimate it, or the
+/// path found may not be the cheapest.
+pub fn astar<S, C, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+    let mut nodes = Nodes::new(start, C::default());
+
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        // a cheaper path to this state was found after this entry was queued.
+        if cost > nodes.cost(index) {
+            continue;
+        }
+
+        let state = nodes.state(index).clone();
+        if is_goal(&state) {
+            return Some(nodes.path(index));
+        }
+
+        for (next, step_cost) in neighbours(&state) {
+            let next_cost = cost + step_cost;
+            let estimate = heuristic(&next);
+            if let Some(next_index) = nodes.visit(next, index, next_cost, |old, new| new < old) {
+                queue.push(Reverse((next_cost + estimate, next_cost, next_index)));
+            }
+        }
+    }
+
+    None
+}
+
+/// Visited states of a search, with the cheapest known cost and predecessor of each.
+struct Nodes<S, C> {
+    states: Vec<(S, Option<usize>, C)>,
+    indices: HashMap<S, usize>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Nodes<S, C> {
+    fn new(start: S, cost: C) -> Self {
+        Self {
+            indices: HashMap::from([(start.clone(), 0)]),
+            states: vec![(start, None, cost)],
+        }
+    }
+
+    fn state(&self, index: usize) -> &S {
+        &self.states[index].0
+    }
+
+    fn cost(&self, index: usize) -> C {
+        self.states[index].2
+    }
+
+    /// Records a path to `state`. Returns its index if the state is new, or if `is_better`
+    /// prefers the new cost over the known one.
+    fn visit(
+        &mut self,
+        state: S,
+        parent: usize,
+        cost: C,
+        is_better: impl Fn(C, C) -> bool,
+    ) -> Option<usize> {
+        match self.indices.get(&state) {
+            Some(&index) if is_better(self.states[index].2, cost) => {
+                self.states[index].1 = Some(parent);
+                self.states[index].2 = cost;
+                Some(index)
+            }
+            Some(_) => None,
+            None => {
+                let index = self.states.len();
+                self.indices.insert(state.clone(), index);
+                self.states.push((state, Some(parent), cost));
+                Some(index)
+            }
+        }
+    }
+
+    fn path(&self, index: usize) -> Path<S, C> {
+        let cost = self.cost(index);
+        let mut states = vec![];
+        let mut current = Some(index);
+
+        while let Some(index) = current {
+            states.push(self.states[index].0.clone());
+            current = self.states[index].1;
+        }
+
+        states.reverse();
+        Path { states, cost }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Evaluates a recursive function, caching the result for every argument.
+///
+/// The function receives a `recurse` callback to call itself with, e.g. to count paths:
+///
+/// ```ignore
+/// let paths = memoize((0, start), |recurse, (row, col)| {
+///     if row == last_row { 1 } else { recurse((row + 1, col - 1)) + recurse((row + 1, col + 1)) }
+/// });
+/// ```
+pub fn memoize<K, V>(key: K, f: impl Fn(&mut dyn FnMut(K) -> V, K) -> V) -> V
+where
+    K: Clone + Eq + Hash,
+    V: Clone,
+{
+    Memo::new().get(key, &f)
+}
+
+/// A cache of the results of a recursive function, see [`memoize`].
+/// Keep it around to reuse results across several calls.
+#[derive(Clone, Debug)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self {
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Returns the cached result for `key`, or evaluates `f` for it.
+    pub fn get(&mut self, key: K, f: &impl Fn(&mut dyn FnMut(K) -> V, K) -> V) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            return value.clone();
+        }
+
+        let value = f(&mut |k| self.get(k, f), key.clone());
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Memo, Path, astar, bfs, bfs_distances, dijkstra, memoize};
+    use crate::grid::Grid;
+
+    const MAZE: &str = "S.#.\n..#.\n.#..\n...E\n";
+
+    #[test]
+    fn finds_shortest_paths() {
+        let grid = Grid::from_chars(MAZE);
+        let start = grid.find(&'S').unwrap();
+        let end = grid.find(&'E').unwrap();
+        let open = |pos: &(usize, usize)| {
+            grid.neighbours_4(*pos)
+                .filter(|&n| grid[n] != '#')
+                .collect::<Vec<_>>()
+        };
+
+        let path = bfs(start, open, |&pos| pos == end).unwrap();
+        assert_eq!(path.cost, 6);
+        assert_eq!(path.states.first(), Some(&start));
+        assert_eq!(path.states.last(), Some(&end));
+
+        let distances = bfs_distances(start, open);
+        assert_eq!(distances[&end], 6);
+        assert_eq!(distances.get(&(0, 3)), Some(&9));
+
+        assert_eq!(bfs(start, open, |&pos| pos == (0, 2)), None);
+    }
+
+    #[test]
+    fn finds_cheapest_paths() {
+        // going straight to 3 costs 10, the detour over 1 and 2 only 3.
+        let edges = |&n: &u32| match n {
+            0 => vec![(3, 10), (1, 1)],
+            1 => vec![(2, 1)],
+            2 => vec![(3, 1)],
+            _ => vec![],
+        };
+
+        let expected = Path {
+            states: vec![0, 1, 2, 3],
+            cost: 3,
+        };
+        assert_eq!(dijkstra(0, edges, |&n| n == 3), Some(expected.clone()));
+        assert_eq!(astar(0, edges, |&n| 3 - n, |&n| n == 3), Some(expected));
+        assert_eq!(dijkstra(0, edges, |&n| n == 4), None);
+    }
+
+    #[test]
+    fn memoizes_recursion() {
+        let fib = |n: u64| {
+            memoize(n, |recurse, n| {
+                if n < 2 {
+                    n
+                } else {
+                    recurse(n - 1) + recurse(n - 2)
+                }
+            })
+        };
+        assert_eq!(fib(90), 2_880_067_194_370_816_120);
+
+        let mut memo = Memo::new();
+        let double = |_: &mut dyn FnMut(u64) -> u64, n: u64| n * 2;
+        assert_eq!(memo.get(21, &double), 42);
+        assert_eq!(memo.len(), 1);
+    }
+}
@@ -0,0 +1,431 @@
+use std::{collections::HashMap, fs, io::Error, path::PathBuf, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{Day, Year, get_data_dir};
+
+/// Represents the accepted answers and rejected guesses for a single day.
+#[derive(Clone, Debug)]
+pub struct Answer {
+    pub day: Day,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+    pub wrong_guesses: Vec<WrongGuess>,
+}
+
+/// An answer that was rejected by the server.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WrongGuess {
+    pub part: u8,
+    pub value: String,
+    pub hint: Option<Hint>,
+}
+
+/// The direction hint given for a wrong numeric answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// Result of comparing a computed answer with the accepted one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AnswerCheck {
+    Correct,
+    Wrong { expected: String },
+    Unknown,
+}
+
+/// Represents the accepted answers for a set of days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    pub data: Vec<Answer>,
+}
+
+impl Answers {
+    /// Dehydrate answers to the JSON file of an event.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        fs::create_dir_all(get_data_dir(year, ""))?;
+        let mut file = fs::File::create(get_path(year))?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate answers from the JSON file of an event. If not present, returns empty answers.
+    pub fn read_from_file(year: Year) -> Self {
+        fs::read_to_string(get_path(year))
+            .map_err(|x| x.to_string())
+            .and_then(Answers::try_from)
+            .unwrap_or_default()
+    }
+
+    /// Returns the accepted answer for a part, if known.
+    pub fn get(&self, day: Day, part: u8) -> Option<&str> {
+        let answer = self.data.iter().find(|a| a.day == day)?;
+        match part {
+            1 => answer.part_1.as_deref(),
+            2 => answer.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Records the accepted answer for a part, replacing a previous one.
+    pub fn set(&mut self, day: Day, part: u8, value: &str) {
+        let answer = self.entry(day);
+        match part {
+            1 => answer.part_1 = Some(value.into()),
+            2 => answer.part_2 = Some(value.into()),
+            _ => {}
+        }
+    }
+
+    /// Records an answer that was rejected by the server.
+    pub fn add_wrong_guess(&mut self, day: Day, part: u8, value: &str, hint: Option<Hint>) {
+        let answer = self.entry(day);
+        if !answer
+            .wrong_guesses
+            .iter()
+            .any(|g| g.part == part && g.value == value)
+        {
+            answer.wrong_guesses.push(WrongGuess {
+                part,
+                value: value.into(),
+                hint,
+            });
+        }
+    }
+
+    /// Checks whether submitting an answer could be accepted, given what we already know.
+    /// Returns the reason if the submission is known to be pointless.
+    pub fn validate_submission(&self, day: Day, part: u8, value: &str) -> Result<(), String> {
+        if let Some(accepted) = self.get(day, part) {
+            return Err(if accepted == value {
+                format!("{value} was already accepted.")
+            } else {
+                format!("part {part} was already solved with {accepted}.")
+            });
+        }
+
+        let Some(answer) = self.data.iter().find(|a| a.day == day) else {
+            return Ok(());
+        };
+
+        let number = value.parse::<i128>().ok();
+
+        for guess in answer.wrong_guesses.iter().filter(|g| g.part == part) {
+            if guess.value == value {
+                return Err(format!("{value} was already rejected."));
+            }
+
+            let (Some(number), Ok(bound)) = (number, guess.value.parse::<i128>()) else {
+                continue;
+            };
+
+            match guess.hint {
+                Some(Hint::TooHigh) if number >= bound => {
+                    return Err(format!(
+                        "{value} is not lower than {bound}, which was too high."
+                    ));
+                }
+                Some(Hint::TooLow) if number <= bound => {
+                    return Err(format!(
+                        "{value} is not higher than {bound}, which was too low."
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Compares a computed answer with the accepted answer for a part.
+    pub fn check(&self, day: Day, part: u8, value: Option<&str>) -> AnswerCheck {
+        match self.get(day, part) {
+            None => AnswerCheck::Unknown,
+            Some(expected) if Some(expected) == value => AnswerCheck::Correct,
+            Some(expected) => AnswerCheck::Wrong {
+                expected: expected.into(),
+            },
+        }
+    }
+
+    fn entry(&mut self, day: Day) -> &mut Answer {
+        let index = match self.data.iter().position(|a| a.day == day) {
+            Some(index) => index,
+            None => {
+                self.data.push(Answer {
+                    day,
+                    part_1: None,
+                    part_2: None,
+                    wrong_guesses: vec![],
+                });
+                self.data.sort_unstable_by_key(|a| a.day);
+                self.data.iter().position(|a| a.day == day).unwrap()
+            }
+        };
+
+        &mut self.data[index]
+    }
+}
+
+fn get_path(year: Year) -> PathBuf {
+    get_data_dir(year, "").join("answers.json")
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Answers> for JsonValue {
+    fn from(value: Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Answers {
+            data: json_data
+                .iter()
+                .map(Answer::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Answer> for JsonValue {
+    fn from(value: &Answer) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+
+        map.insert(
+            "part_1".into(),
+            match value.part_1.clone() {
+                Some(x) => JsonValue::String(x),
+                None => JsonValue::Null,
+            },
+        );
+
+        map.insert(
+            "part_2".into(),
+            match value.part_2.clone() {
+                Some(x) => JsonValue::String(x),
+                None => JsonValue::Null,
+            },
+        );
+
+        map.insert(
+            "wrong_guesses".into(),
+            JsonValue::Array(value.wrong_guesses.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Answer {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected answer to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected answer.day to be a Day struct.")?;
+
+        let part_1 = json
+            .get("part_1")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected answer.part_1 to be null or string.")?;
+
+        let part_2 = json
+            .get("part_2")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected answer.part_2 to be null or string.")?;
+
+        // NOTE: files written before wrong guesses were tracked do not have this key.
+        let wrong_guesses = match json.get("wrong_guesses") {
+            None => vec![],
+            Some(v) => v
+                .get::<Vec<JsonValue>>()
+                .ok_or("Expected answer.wrong_guesses to be an array.")?
+                .iter()
+                .map(WrongGuess::try_from)
+                .collect::<Result<_, _>>()?,
+        };
+
+        Ok(Answer {
+            day,
+            part_1: part_1.cloned(),
+            part_2: part_2.cloned(),
+            wrong_guesses,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&WrongGuess> for JsonValue {
+    fn from(value: &WrongGuess) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("value".into(), JsonValue::String(value.value.clone()));
+        map.insert(
+            "hint".into(),
+            match value.hint {
+                Some(Hint::TooHigh) => JsonValue::String("too_high".into()),
+                Some(Hint::TooLow) => JsonValue::String("too_low".into()),
+                None => JsonValue::Null,
+            },
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for WrongGuess {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected wrong guess to be a JSON object.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected wrong_guess.part to be a number.")? as u8;
+
+        let value = json
+            .get("value")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected wrong_guess.value to be a string.")?;
+
+        let hint = match json.get("hint").and_then(|v| v.get::<String>()) {
+            Some(x) if x == "too_high" => Some(Hint::TooHigh),
+            Some(x) if x == "too_low" => Some(Hint::TooLow),
+            _ => None,
+        };
+
+        Ok(WrongGuess {
+            part,
+            value: value.clone(),
+            hint,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use crate::day;
+
+    use super::{AnswerCheck, Answers, Hint};
+
+    #[test]
+    fn deserializes_answers() {
+        let json = r#"{ "data": [{ "day": "01", "part_1": "42", "part_2": null }] }"#.to_string();
+        let answers = Answers::try_from(json).unwrap();
+        assert_eq!(answers.get(day!(1), 1), Some("42"));
+        assert_eq!(answers.get(day!(1), 2), None);
+        assert_eq!(answers.get(day!(2), 1), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_answers() {
+        let json = r#"{ "data": [{ "day": "01" }] }"#.to_string();
+        Answers::try_from(json).unwrap();
+    }
+
+    #[test]
+    fn sets_answers_in_day_order() {
+        let mut answers = Answers::default();
+        answers.set(day!(4), 2, "7");
+        answers.set(day!(2), 1, "3");
+        answers.set(day!(4), 1, "5");
+        assert_eq!(answers.data.len(), 2);
+        assert_eq!(answers.data[0].day, day!(2));
+        assert_eq!(answers.get(day!(4), 1), Some("5"));
+        assert_eq!(answers.get(day!(4), 2), Some("7"));
+    }
+
+    #[test]
+    fn checks_answers() {
+        let mut answers = Answers::default();
+        answers.set(day!(1), 1, "42");
+        assert_eq!(answers.check(day!(1), 1, Some("42")), AnswerCheck::Correct);
+        assert_eq!(
+            answers.check(day!(1), 1, Some("41")),
+            AnswerCheck::Wrong {
+                expected: "42".into()
+            }
+        );
+        assert_eq!(
+            answers.check(day!(1), 1, None),
+            AnswerCheck::Wrong {
+                expected: "42".into()
+            }
+        );
+        assert_eq!(answers.check(day!(1), 2, Some("1")), AnswerCheck::Unknown);
+    }
+
+    #[test]
+    fn roundtrips_wrong_guesses() {
+        let mut answers = Answers::default();
+        answers.add_wrong_guess(day!(1), 1, "100", Some(Hint::TooHigh));
+        answers.add_wrong_guess(day!(1), 1, "100", Some(Hint::TooHigh));
+        answers.add_wrong_guess(day!(1), 2, "abc", None);
+        let json = tinyjson::JsonValue::from(answers).stringify().unwrap();
+        let answers = Answers::try_from(json).unwrap();
+        assert_eq!(answers.data[0].wrong_guesses.len(), 2);
+        assert_eq!(answers.data[0].wrong_guesses[0].hint, Some(Hint::TooHigh));
+        assert_eq!(answers.data[0].wrong_guesses[1].hint, None);
+    }
+
+    #[test]
+    fn refuses_known_submissions() {
+        let mut answers = Answers::default();
+        answers.add_wrong_guess(day!(1), 1, "100", Some(Hint::TooHigh));
+        answers.add_wrong_guess(day!(1), 1, "10", Some(Hint::TooLow));
+        answers.add_wrong_guess(day!(1), 1, "50", None);
+        answers.set(day!(1), 2, "7");
+
+        assert!(answers.validate_submission(day!(1), 1, "42").is_ok());
+        assert!(answers.validate_submission(day!(1), 1, "50").is_err());
+        assert!(answers.validate_submission(day!(1), 1, "100").is_err());
+        assert!(answers.validate_submission(day!(1), 1, "150").is_err());
+        assert!(answers.validate_submission(day!(1), 1, "5").is_err());
+        assert!(answers.validate_submission(day!(1), 2, "7").is_err());
+        assert!(answers.validate_submission(day!(1), 2, "8").is_err());
+        assert!(answers.validate_submission(day!(2), 1, "1").is_ok());
+    }
+}
@@ -1,10 +1,11 @@
 /// Wrapper module around the "aoc-cli" command-line.
 use std::{
     fmt::Display,
+    fs,
     process::{Command, Output, Stdio},
 };
 
-use crate::template::Day;
+use crate::template::{Day, Year, get_data_dir};
 
 #[derive(Debug)]
 pub enum AocCommandError {
@@ -25,6 +26,62 @@ impl Display for AocCommandError {
     }
 }
 
+/// Outcome of submitting an answer, as reported by aoc-cli.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubmissionOutcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// The server rate-limited the submission. Holds the remaining wait in seconds, if known.
+    Wait(Option<u64>),
+    AlreadySolved,
+    Unknown,
+}
+
+impl SubmissionOutcome {
+    /// Classifies the text printed by aoc-cli after a submission.
+    pub fn parse(text: &str) -> Self {
+        let text = text.to_lowercase();
+
+        if text.contains("that's the right answer") {
+            SubmissionOutcome::Correct
+        } else if text.contains("your answer is too high") {
+            SubmissionOutcome::TooHigh
+        } else if text.contains("your answer is too low") {
+            SubmissionOutcome::TooLow
+        } else if text.contains("not the right answer") {
+            SubmissionOutcome::Wrong
+        } else if text.contains("answer too recently") {
+            SubmissionOutcome::Wait(parse_wait_seconds(&text))
+        } else if text.contains("did you already complete it") {
+            SubmissionOutcome::AlreadySolved
+        } else {
+            SubmissionOutcome::Unknown
+        }
+    }
+}
+
+/// Parses a wait hint like "you have 1m 30s left to wait" into seconds.
+fn parse_wait_seconds(text: &str) -> Option<u64> {
+    let start = text.rfind("you have ")? + "you have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+
+    text[start..end]
+        .split_whitespace()
+        .map(|token| {
+            // NOTE: server text may contain multibyte characters, so do not split at byte offsets.
+            let unit = token.chars().last()?;
+            let value: u64 = token.strip_suffix(['h', 'm', 's'])?.parse().ok()?;
+            match unit {
+                'h' => Some(value * 3600),
+                'm' => Some(value * 60),
+                _ => Some(value),
+            }
+        })
+        .sum()
+}
+
 pub fn check() -> Result<(), AocCommandError> {
     Command::new("aoc")
         .arg("-V")
@@ -33,8 +90,8 @@ pub fn check() -> Result<(), AocCommandError> {
     Ok(())
 }
 
-pub fn read(day: Day) -> Result<Output, AocCommandError> {
-    let puzzle_path = get_puzzle_path(day);
+pub fn read(year: Year, day: Day) -> Result<Output, AocCommandError> {
+    let puzzle_path = get_puzzle_path(year, day);
 
     let args = build_args(
         "read",
@@ -43,65 +100,105 @@ pub fn read(day: Day) -> Result<Output, AocCommandError> {
             "--puzzle-file".into(),
             puzzle_path,
         ],
+        year,
         day,
     );
 
     call_aoc_cli(&args)
 }
 
-pub fn download(day: Day) -> Result<Output, AocCommandError> {
-    let input_path = get_input_path(day);
-    let puzzle_path = get_puzzle_path(day);
+/// Downloads the input and / or the puzzle of a day.
+pub fn download(
+    year: Year,
+    day: Day,
+    input: bool,
+    puzzle: bool,
+) -> Result<Output, AocCommandError> {
+    let input_path = get_input_path(year, day);
+    let puzzle_path = get_puzzle_path(year, day);
 
-    let args = build_args(
-        "download",
-        &[
-            "--overwrite".into(),
-            "--input-file".into(),
-            input_path.to_string(),
-            "--puzzle-file".into(),
-            puzzle_path.to_string(),
-        ],
-        day,
-    );
+    // aoc-cli does not create missing directories, e.g. for a new event.
+    for folder in ["inputs", "puzzles"] {
+        let _ = fs::create_dir_all(get_data_dir(year, folder));
+    }
+
+    let mut args: Vec<String> = vec![
+        "--overwrite".into(),
+        "--input-file".into(),
+        input_path.to_string(),
+        "--puzzle-file".into(),
+        puzzle_path.to_string(),
+    ];
+
+    match (input, puzzle) {
+        (true, false) => args.push("--input-only".into()),
+        (false, true) => args.push("--puzzle-only".into()),
+        _ => {}
+    }
+
+    let args = build_args("download", &args, year, day);
 
     let output = call_aoc_cli(&args)?;
     println!("---");
-    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    if input {
+        println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    }
+    if puzzle {
+        println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    }
     Ok(output)
 }
 
-pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandError> {
+/// Submits an answer. The output of aoc-cli is captured (and echoed) so it can be classified.
+pub fn submit(year: Year, day: Day, part: u8, result: &str) -> Result<Output, AocCommandError> {
     // workaround: the argument order is inverted for submit.
-    let mut args = build_args("submit", &[], day);
+    let mut args = build_args("submit", &[], year, day);
     args.push(part.to_string());
     args.push(result.to_string());
-    call_aoc_cli(&args)
+
+    let output = Command::new("aoc")
+        .args(&args)
+        .output()
+        .map_err(|_| AocCommandError::CommandNotCallable)?;
+
+    print!("{}", String::from_utf8_lossy(&output.stdout));
+    eprint!("{}", String::from_utf8_lossy(&output.stderr));
+
+    if output.status.success() {
+        Ok(output)
+    } else {
+        Err(AocCommandError::BadExitStatus(output))
+    }
 }
 
-fn get_input_path(day: Day) -> String {
-    format!("data/inputs/{day}.txt")
+/// Classifies the captured output of [`submit`].
+pub fn submission_outcome(output: &Output) -> SubmissionOutcome {
+    SubmissionOutcome::parse(&format!(
+        "{}\n{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    ))
 }
 
-fn get_puzzle_path(day: Day) -> String {
-    format!("data/puzzles/{day}.md")
+fn get_input_path(year: Year, day: Day) -> String {
+    get_data_dir(year, "inputs")
+        .join(format!("{day}.txt"))
+        .display()
+        .to_string()
 }
 
-fn get_year() -> Option<u16> {
-    match std::env::var("AOC_YEAR") {
-        Ok(x) => x.parse().ok().or(None),
-        Err(_) => None,
-    }
+fn get_puzzle_path(year: Year, day: Day) -> String {
+    get_data_dir(year, "puzzles")
+        .join(format!("{day}.md"))
+        .display()
+        .to_string()
 }
 
-fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
+fn build_args(command: &str, args: &[String], year: Year, day: Day) -> Vec<String> {
     let mut cmd_args = args.to_vec();
 
-    if let Some(year) = get_year() {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
-    }
+    cmd_args.push("--year".into());
+    cmd_args.push(year.to_string());
 
     cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
 
@@ -123,3 +220,63 @@ fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
         Err(AocCommandError::BadExitStatus(output))
     }
 }
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::SubmissionOutcome;
+
+    #[test]
+    fn classifies_submission_outcomes() {
+        assert_eq!(
+            SubmissionOutcome::parse("That's the right answer! You are one gold star closer."),
+            SubmissionOutcome::Correct
+        );
+        assert_eq!(
+            SubmissionOutcome::parse(
+                "That's not the right answer; your answer is too high. Please wait one minute."
+            ),
+            SubmissionOutcome::TooHigh
+        );
+        assert_eq!(
+            SubmissionOutcome::parse("That's not the right answer; your answer is too low."),
+            SubmissionOutcome::TooLow
+        );
+        assert_eq!(
+            SubmissionOutcome::parse("That's not the right answer. If you're stuck, ..."),
+            SubmissionOutcome::Wrong
+        );
+        assert_eq!(
+            SubmissionOutcome::parse(
+                "You don't seem to be solving the right level. Did you already complete it?"
+            ),
+            SubmissionOutcome::AlreadySolved
+        );
+        assert_eq!(
+            SubmissionOutcome::parse("Something unexpected"),
+            SubmissionOutcome::Unknown
+        );
+    }
+
+    #[test]
+    fn parses_wait_durations() {
+        assert_eq!(
+            SubmissionOutcome::parse(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                before trying again. You have 1m 30s left to wait."
+            ),
+            SubmissionOutcome::Wait(Some(90))
+        );
+        assert_eq!(
+            SubmissionOutcome::parse("You gave an answer too recently. You have 42s left to wait."),
+            SubmissionOutcome::Wait(Some(42))
+        );
+        assert_eq!(
+            SubmissionOutcome::parse("You gave an answer too recently."),
+            SubmissionOutcome::Wait(None)
+        );
+        assert_eq!(
+            SubmissionOutcome::parse("You gave an answer too recently. You have 5é left to wait."),
+            SubmissionOutcome::Wait(None)
+        );
+    }
+}
@@ -0,0 +1,286 @@
+/// HTTP client for the Advent of Code website.
+///
+/// Authenticates with the session cookie of a logged-in browser, which is read from the
+/// `AOC_SESSION` environment variable or from `~/.adventofcode.session` (the file aoc-cli uses).
+/// The base URL can be overridden with `AOC_BASE_URL`, e.g. to run against a local mock server.
+/// Requests identify themselves with a User-Agent, which can be overridden with `AOC_USER_AGENT`.
+use std::{env, fmt::Display, fs, path::PathBuf};
+
+use ureq::Agent;
+
+use crate::template::{Day, Year, aoc_cli::SubmissionOutcome, markdown};
+
+pub const SESSION_ENV: &str = "AOC_SESSION";
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+pub const USER_AGENT_ENV: &str = "AOC_USER_AGENT";
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const SESSION_FILE_NAME: &str = ".adventofcode.session";
+const DEFAULT_USER_AGENT: &str = concat!(
+    "github.com/fspoettel/advent-of-code-rust ",
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION")
+);
+
+#[derive(Debug)]
+pub enum ClientError {
+    SessionNotFound,
+    /// The server responded with a non-success status code.
+    Status(u16),
+    Request(String),
+    Parse(String),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::SessionNotFound => write!(
+                f,
+                "no session cookie found. Set {SESSION_ENV} or create ~/{SESSION_FILE_NAME}."
+            ),
+            ClientError::Status(400) => {
+                write!(
+                    f,
+                    "the server rejected the request. Is the session cookie valid?"
+                )
+            }
+            ClientError::Status(404) => write!(f, "the puzzle is not available (yet)."),
+            ClientError::Status(status) => write!(f, "the server responded with status {status}."),
+            ClientError::Request(e) => write!(f, "request failed: {e}"),
+            ClientError::Parse(e) => write!(f, "unexpected response: {e}"),
+        }
+    }
+}
+
+impl From<ureq::Error> for ClientError {
+    fn from(e: ureq::Error) -> Self {
+        ClientError::Request(e.to_string())
+    }
+}
+
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str, user_agent: &str) -> Self {
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .user_agent(user_agent)
+            .build()
+            .into();
+
+        Self {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+        }
+    }
+
+    /// Creates a client from the session cookie, base URL and User-Agent configured in the
+    /// environment.
+    pub fn from_env() -> Result<Self, ClientError> {
+        let session = match env::var(SESSION_ENV) {
+            Ok(session) if !session.trim().is_empty() => session,
+            _ => get_session_path()
+                .and_then(|path| fs::read_to_string(path).ok())
+                .filter(|session| !session.trim().is_empty())
+                .ok_or(ClientError::SessionNotFound)?,
+        };
+
+        let base_url = env::var(BASE_URL_ENV).unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        let user_agent = env::var(USER_AGENT_ENV).unwrap_or_else(|_| DEFAULT_USER_AGENT.into());
+
+        Ok(Self::new(&base_url, &session, &user_agent))
+    }
+
+    /// Fetches the puzzle input of a day.
+    pub fn input(&self, year: Year, day: Day) -> Result<String, ClientError> {
+        self.get(&format!("{}/input", self.day_url(year, day)))
+    }
+
+    /// Fetches the puzzle page of a day as HTML.
+    pub fn puzzle_html(&self, year: Year, day: Day) -> Result<String, ClientError> {
+        self.get(&self.day_url(year, day))
+    }
+
+    /// Fetches the puzzle description of a day as Markdown. Includes part two once it is unlocked.
+    pub fn puzzle_markdown(&self, year: Year, day: Day) -> Result<String, ClientError> {
+        let html = self.puzzle_html(year, day)?;
+        markdown::extract_descriptions(&html)
+            .ok_or_else(|| ClientError::Parse("puzzle page has no description.".into()))
+    }
+
+    /// Submits an answer. Returns the outcome together with the message of the server.
+    pub fn submit(
+        &self,
+        year: Year,
+        day: Day,
+        part: u8,
+        answer: &str,
+    ) -> Result<(SubmissionOutcome, String), ClientError> {
+        let url = format!("{}/answer", self.day_url(year, day));
+        let level = part.to_string();
+
+        let mut response = self
+            .agent
+            .post(&url)
+            .header("Cookie", self.cookie())
+            .send_form([("level", level.as_str()), ("answer", answer)])?;
+
+        if !response.status().is_success() {
+            return Err(ClientError::Status(response.status().as_u16()));
+        }
+
+        let html = response.body_mut().read_to_string()?;
+        let message = markdown::extract_message(&html)
+            .ok_or_else(|| ClientError::Parse("submission response has no message.".into()))?;
+
+        Ok((SubmissionOutcome::parse(&message), message))
+    }
+
+    fn get(&self, url: &str) -> Result<String, ClientError> {
+        let mut response = self.agent.get(url).header("Cookie", self.cookie()).call()?;
+
+        if !response.status().is_success() {
+            return Err(ClientError::Status(response.status().as_u16()));
+        }
+
+        Ok(response.body_mut().read_to_string()?)
+    }
+
+    fn day_url(&self, year: Year, day: Day) -> String {
+        format!("{}/{year}/day/{}", self.base_url, day.into_inner())
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+}
+
+fn get_session_path() -> Option<PathBuf> {
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(|home| PathBuf::from(home).join(SESSION_FILE_NAME))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+    };
+
+    use super::{Client, ClientError};
+    use crate::{
+        day,
+        template::{Year, aoc_cli::SubmissionOutcome},
+    };
+
+    const YEAR: Year = Year::new(2025).unwrap();
+
+    /// Serves one canned response per request and hands back the raw requests.
+    fn mock_server(responses: Vec<(u16, &'static str)>) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(length) = line.to_lowercase().strip_prefix("content-length:") {
+                        content_length = length.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" {
+                        break;
+                    }
+                }
+
+                let mut body_bytes = vec![0; content_length];
+                reader.read_exact(&mut body_bytes).unwrap();
+                request.push_str(&String::from_utf8(body_bytes).unwrap());
+                // the test may not be interested in the request.
+                tx.send(request).ok();
+
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 {status} OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        });
+
+        (url, rx)
+    }
+
+    #[test]
+    fn fetches_inputs_with_session() {
+        let (url, requests) = mock_server(vec![(200, "L68\nL30\n")]);
+        let client = Client::new(&url, "abc123\n", "me@example.com");
+
+        assert_eq!(client.input(YEAR, day!(1)).unwrap(), "L68\nL30\n");
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("GET /2025/day/1/input "));
+        assert!(request.contains("cookie: session=abc123\r\n"));
+        assert!(request.contains("user-agent: me@example.com\r\n"));
+    }
+
+    #[test]
+    fn fetches_puzzles_as_markdown() {
+        let (url, _) = mock_server(vec![(
+            200,
+            "<main><article class=\"day-desc\"><h2>--- Day 3 ---</h2><p>Hi.</p></article></main>",
+        )]);
+        let client = Client::new(&url, "abc123", "me@example.com");
+
+        assert_eq!(
+            client.puzzle_markdown(YEAR, day!(3)).unwrap(),
+            "## --- Day 3 ---\n\nHi.\n"
+        );
+    }
+
+    #[test]
+    fn submits_answers() {
+        let (url, requests) = mock_server(vec![(
+            200,
+            "<main><article><p>That's not the right answer; your answer is too high.</p></article></main>",
+        )]);
+        let client = Client::new(&url, "abc123", "me@example.com");
+
+        let (outcome, message) = client.submit(YEAR, day!(8), 2, "42").unwrap();
+        assert_eq!(outcome, SubmissionOutcome::TooHigh);
+        assert!(message.starts_with("That's not the right answer"));
+
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("POST /2025/day/8/answer "));
+        assert!(request.ends_with("level=2&answer=42"));
+    }
+
+    #[test]
+    fn reports_error_status() {
+        let (url, _) = mock_server(vec![(404, "Not found")]);
+        let client = Client::new(&url, "abc123", "me@example.com");
+
+        assert!(matches!(
+            client.input(YEAR, day!(12)),
+            Err(ClientError::Status(404))
+        ));
+    }
+}
@@ -1,5 +1,19 @@
-use crate::template::{all_days, run_multi::run_multi};
+use crate::template::{Limits, all_days, registry::Registry, run_multi::run_multi};
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+pub fn handle(
+    is_release: bool,
+    is_checked: bool,
+    registry: Option<&Registry>,
+    jobs: usize,
+    limits: Limits,
+) {
+    run_multi(
+        &all_days().collect(),
+        is_release,
+        false,
+        is_checked,
+        registry,
+        jobs,
+        limits,
+    );
 }
@@ -1,7 +1,34 @@
-use crate::template::{Day, aoc_cli};
-use std::process;
+use crate::template::{
+    Day, Year, aoc_cli,
+    client::{Client, ClientError},
+    downloads::{self, Downloads},
+};
+use std::{fs, path::Path, process};
 
 pub fn handle(day: Day) {
+    let year = Year::current();
+    let mut downloads = Downloads::read_from_file();
+
+    if downloads::cached_input(year, day).is_some() && downloads.is_puzzle_fresh(year, day) {
+        println!("🎄 Input and puzzle of day {day} are already downloaded, skipping.");
+        return;
+    }
+
+    match Client::from_env().and_then(|client| download(&client, &mut downloads, year, day)) {
+        Ok(()) => return,
+        // without a session cookie, aoc-cli may still be configured.
+        Err(ClientError::SessionNotFound) => {}
+        Err(e) => eprintln!("failed to download puzzle: {e} Falling back to aoc-cli."),
+    }
+
+    // the native client may have fetched some of the files before failing.
+    let input = downloads::cached_input(year, day).is_none();
+    let puzzle = !downloads.is_puzzle_fresh(year, day);
+
+    if !input && !puzzle {
+        return;
+    }
+
     if aoc_cli::check().is_err() {
         eprintln!(
             "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
@@ -9,8 +36,58 @@ pub fn handle(day: Day) {
         process::exit(1);
     }
 
-    if let Err(e) = aoc_cli::download(day) {
+    downloads.wait_for_slot();
+    if let Err(e) = aoc_cli::download(year, day, input, puzzle) {
         eprintln!("failed to call aoc-cli: {e}");
         process::exit(1);
     };
+
+    if input {
+        downloads.record_input(year, day);
+    }
+    if puzzle {
+        downloads.record_puzzle(year, day);
+    }
+}
+
+fn download(
+    client: &Client,
+    downloads: &mut Downloads,
+    year: Year,
+    day: Day,
+) -> Result<(), ClientError> {
+    if downloads::cached_input(year, day).is_some() {
+        println!("🎄 Input of day {day} is already downloaded, skipping.");
+    } else {
+        downloads.wait_for_slot();
+        let input = client.input(year, day)?;
+        let path = downloads::get_input_path(year, day);
+        write_file(&path, &input);
+        downloads.record_input(year, day);
+        println!("🎄 Successfully wrote input to \"{}\".", path.display());
+    }
+
+    if downloads.is_puzzle_fresh(year, day) {
+        println!("🎄 Puzzle of day {day} is already downloaded, skipping.");
+    } else {
+        downloads.wait_for_slot();
+        let puzzle = client.puzzle_markdown(year, day)?;
+        let path = downloads::get_puzzle_path(year, day);
+        write_file(&path, &puzzle);
+        downloads.record_puzzle(year, day);
+        println!("🎄 Successfully wrote puzzle to \"{}\".", path.display());
+    }
+
+    Ok(())
+}
+
+fn write_file(path: &Path, contents: &str) {
+    if let Some(dir) = path.parent() {
+        let _ = fs::create_dir_all(dir);
+    }
+
+    if let Err(e) = fs::write(path, contents) {
+        eprintln!("failed to write \"{}\": {e}", path.display());
+        process::exit(1);
+    }
 }
@@ -1,8 +1,41 @@
-use std::process;
+use std::{fs, process};
 
-use crate::template::{Day, aoc_cli};
+use crate::template::{
+    Day, Year, aoc_cli,
+    client::{Client, ClientError},
+    downloads::{self, Downloads},
+};
 
 pub fn handle(day: Day) {
+    let year = Year::current();
+    let mut downloads = Downloads::read_from_file();
+
+    if downloads.is_puzzle_fresh(year, day)
+        && let Some(puzzle) = downloads::cached_puzzle(year, day)
+    {
+        print!("{puzzle}");
+        return;
+    }
+
+    match Client::from_env().and_then(|client| {
+        downloads.wait_for_slot();
+        client.puzzle_markdown(year, day)
+    }) {
+        Ok(puzzle) => {
+            let path = downloads::get_puzzle_path(year, day);
+            let _ = fs::create_dir_all(path.parent().unwrap());
+            match fs::write(&path, &puzzle) {
+                Ok(()) => downloads.record_puzzle(year, day),
+                Err(e) => eprintln!("failed to cache puzzle: {e}"),
+            }
+            print!("{puzzle}");
+            return;
+        }
+        // without a session cookie, aoc-cli may still be configured.
+        Err(ClientError::SessionNotFound) => {}
+        Err(e) => eprintln!("failed to fetch puzzle: {e} Falling back to aoc-cli."),
+    }
+
     if aoc_cli::check().is_err() {
         eprintln!(
             "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
@@ -10,7 +43,8 @@ pub fn handle(day: Day) {
         process::exit(1);
     }
 
-    if let Err(e) = aoc_cli::read(day) {
+    downloads.wait_for_slot();
+    if let Err(e) = aoc_cli::read(year, day) {
         eprintln!("failed to call aoc-cli: {e}");
         process::exit(1);
     };
@@ -1,13 +1,14 @@
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    path::Path,
     process,
 };
 
-use crate::template::Day;
-
-const MODULE_TEMPLATE: &str =
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+use crate::template::{
+    Day, Year, downloads, examples, get_data_dir,
+    templates::{Placeholders, Template},
+};
 
 fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
     let mut file = OpenOptions::new();
@@ -27,10 +28,54 @@ fn create_file(path: &str) -> Result<File, std::io::Error> {
         .open(path)
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+fn write_example(path: &str, contents: Option<&str>) {
+    let result = create_file(path)
+        .and_then(|mut file| file.write_all(contents.unwrap_or_default().as_bytes()));
+
+    match (result, contents) {
+        (Ok(()), Some(_)) => {
+            println!("Created example file \"{path}\" from the puzzle description");
+        }
+        (Ok(()), None) => println!("Created empty example file \"{path}\""),
+        (Err(e), _) => {
+            eprintln!("Failed to create example file: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+pub fn handle(day: Day, overwrite: bool, template: Option<&str>) {
+    let year = Year::current();
+
+    let template = match Template::load(template) {
+        Ok(template) => template,
+        Err(e) => {
+            eprintln!("Failed to load template: {e}");
+            process::exit(1);
+        }
+    };
+    let input_dir = get_data_dir(year, "inputs");
+    let example_dir = get_data_dir(year, "examples");
+
+    for dir in [&input_dir, &example_dir] {
+        if let Err(e) = fs::create_dir_all(dir) {
+            eprintln!("Failed to create data directory: {e}");
+            process::exit(1);
+        }
+    }
+
+    let input_path = input_dir.join(format!("{day}.txt")).display().to_string();
+    let example_path = example_dir.join(format!("{day}.txt")).display().to_string();
+    let example_part_2_path = example_dir
+        .join(format!("{day}-2.txt"))
+        .display()
+        .to_string();
+    let module_path = format!("src/bin/{}.rs", year.bin_name(day));
+
+    // if the puzzle was downloaded already, its title and examples can be filled in.
+    let puzzle = downloads::cached_puzzle(year, day);
+    let examples = puzzle.as_deref().map(examples::extract).unwrap_or_default();
+    let placeholders = Placeholders::new(year, day, puzzle.as_deref(), &examples);
 
     let mut file = match safe_create_file(&module_path, overwrite) {
         Ok(file) => file,
@@ -40,11 +85,7 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
+    match file.write_all(placeholders.render(&template.module).as_bytes()) {
         Ok(()) => {
             println!("Created module file \"{}\"", &module_path);
         }
@@ -54,26 +95,54 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
+    if downloads::cached_input(year, day).is_some() {
+        println!("Kept downloaded input file \"{}\"", &input_path);
+    } else {
+        match create_file(&input_path) {
+            Ok(_) => {
+                println!("Created empty input file \"{}\"", &input_path);
+            }
+            Err(e) => {
+                eprintln!("Failed to create input file: {e}");
+                process::exit(1);
+            }
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+    write_example(&example_path, examples.part_1.input.as_deref());
+    if let Some(input) = examples.separate_part_2_input() {
+        write_example(&example_part_2_path, Some(input));
+    }
+
+    for (path, contents) in &template.extra_files {
+        let path = placeholders.render(path);
+        if let Some(dir) = Path::new(&path).parent() {
+            let _ = fs::create_dir_all(dir);
         }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
+
+        // extra files never replace what scaffolding (or the user) created already.
+        match safe_create_file(&path, false) {
+            Ok(mut file) => match file.write_all(placeholders.render(contents).as_bytes()) {
+                Ok(()) => println!("Created file \"{path}\" from template"),
+                Err(e) => {
+                    eprintln!("Failed to write \"{path}\": {e}");
+                    process::exit(1);
+                }
+            },
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+                println!("Skipped existing file \"{path}\"");
+            }
+            Err(e) => {
+                eprintln!("Failed to create \"{path}\": {e}");
+                process::exit(1);
+            }
         }
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    if year == Year::CONFIGURED {
+        println!("🎄 Type `cargo solve {day}` to run your solution.");
+    } else {
+        println!("🎄 Type `cargo solve {day} --year {year}` to run your solution.");
+    }
 }
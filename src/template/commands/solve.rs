@@ -1,9 +1,27 @@
-use std::process::{Command, Stdio};
+use std::{
+    fs,
+    process::{self, Command, Stdio},
+};
 
-use crate::template::Day;
+use crate::template::{
+    Day, Limits, Year,
+    report::{self, REPORT_FILE_ENV},
+    run_multi::{Error, child_commands},
+};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+pub fn handle(
+    day: Day,
+    release: bool,
+    dhat: bool,
+    submit_part: Option<u8>,
+    check: bool,
+    limits: Limits,
+) {
+    let mut cmd_args = vec![
+        "run".to_string(),
+        "--bin".to_string(),
+        Year::current().bin_name(day),
+    ];
 
     if dhat {
         cmd_args.extend([
@@ -23,6 +41,18 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         cmd_args.push(submit_part.to_string());
     }
 
+    if check {
+        cmd_args.push("--check".to_string());
+    }
+
+    if !limits.is_empty() {
+        if let Err(e) = run_with_limits(day, &cmd_args, limits) {
+            eprintln!("failed to run day {day}: {e}");
+            process::exit(1);
+        }
+        return;
+    }
+
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
@@ -32,3 +62,50 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
 
     cmd.wait().unwrap();
 }
+
+/// Build the solution, then run its executable directly so it can be stopped by the `limits`.
+/// Exits with a non-zero status if the build fails or the solution is stopped.
+fn run_with_limits(day: Day, cmd_args: &[String], limits: Limits) -> Result<(), Error> {
+    let (cargo_args, solution_args) = cmd_args.split_at(
+        cmd_args
+            .iter()
+            .position(|x| x == "--")
+            .unwrap_or(cmd_args.len()),
+    );
+
+    // skip `run --bin <day>`, which `build_solution` passes itself.
+    let cargo_args: Vec<&str> = cargo_args[3..].iter().map(String::as_str).collect();
+
+    let Some(executable) = child_commands::build_solution(day, &cargo_args)? else {
+        eprintln!("failed to build day {day}.");
+        process::exit(1);
+    };
+
+    // the solution reports every part it finished, so the stopped part can be named.
+    let report_path = child_commands::get_report_path(day);
+    let _ = fs::remove_file(&report_path);
+
+    let mut cmd = Command::new(executable)
+        .args(solution_args.iter().skip(1))
+        .env(REPORT_FILE_ENV, &report_path)
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .spawn()?;
+
+    let failure = limits.wait(&mut cmd)?;
+
+    let reports = report::read_file(&report_path).map_err(Error::Report)?;
+    let _ = fs::remove_file(&report_path);
+
+    if let Some(failure) = failure {
+        let stopped = (1..=2)
+            .filter(|part| !reports.iter().any(|r| r.part == *part))
+            .map(|part| format!("Part {part}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        eprintln!("⏱ Day {day} {stopped} {failure}, stopped the solution.");
+        process::exit(1);
+    }
+
+    Ok(())
+}
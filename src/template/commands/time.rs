@@ -1,15 +1,35 @@
 use std::collections::HashSet;
+use std::process;
 
+use crate::template::history;
+use crate::template::registry::Registry;
+use crate::template::report::part_label;
 use crate::template::run_multi::run_multi;
-use crate::template::timings::Timings;
-use crate::template::{Day, all_days, readme_benchmarks};
+use crate::template::timings::{PartChange, Timings};
+use crate::template::{
+    ANSI_BOLD, ANSI_GREEN, ANSI_RED, ANSI_RESET, Day, Limits, Statistic, Year, all_days,
+    readme_benchmarks,
+};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
+/// Benchmark solutions. If a `compare_threshold` (in percent) is passed, fresh timings are
+/// compared against the stored ones and the process fails if any part got slower than that.
+/// Timings of a failed comparison are not stored.
+pub fn handle(
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    statistic: Statistic,
+    compare_threshold: Option<f64>,
+    registry: Option<&Registry>,
+    limits: Limits,
+) {
+    let year = Year::current();
+    let stored_timings = Timings::read_from_file(year);
 
     let days_to_run = day.map_or_else(
         || {
-            if run_all {
+            // comparing requires fresh timings for every stored day.
+            if run_all || compare_threshold.is_some() {
                 all_days().collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
@@ -21,14 +41,29 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let timings = run_multi(&days_to_run, true, true, false, registry, 1, limits).unwrap();
 
-    if store {
+    let regressions = compare_threshold.map_or(0, |threshold| {
+        print_comparison(
+            &timings.compare(&stored_timings, statistic),
+            statistic,
+            threshold,
+        )
+    });
+
+    // NOTE: storing regressed timings would make them the baseline of the next comparison.
+    if store && regressions > 0 {
+        eprintln!("\nNot storing timings because some parts regressed.");
+    } else if store {
         let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        merged_timings.store_file(year).unwrap();
+
+        if let Err(e) = history::append(year, &timings) {
+            eprintln!("Failed to append to timings history: {e}");
+        }
 
         println!();
-        match readme_benchmarks::update(merged_timings) {
+        match readme_benchmarks::update(year, merged_timings, statistic) {
             Ok(()) => {
                 println!("Stored updated benchmarks.");
             }
@@ -37,4 +72,111 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
             }
         }
     }
+
+    if regressions > 0 {
+        eprintln!(
+            "\n{ANSI_BOLD}✘ {regressions} part(s) slower than the threshold of {:.1}%.{ANSI_RESET}",
+            compare_threshold.unwrap_or_default()
+        );
+        process::exit(1);
+    }
+}
+
+/// Print the change of every part and return how many exceed the threshold.
+fn print_comparison(changes: &[PartChange], statistic: Statistic, threshold: f64) -> usize {
+    println!("\n{ANSI_BOLD}Compared to stored timings ({statistic}){ANSI_RESET}");
+    println!("------");
+
+    if changes.is_empty() {
+        println!("No stored timings to compare against.");
+        return 0;
+    }
+
+    let mut regressions = 0;
+
+    for change in changes {
+        let percent = change.percent();
+        let (color, marker) = if percent > 0_f64 {
+            (ANSI_RED, "▲ slower")
+        } else if percent < 0_f64 {
+            (ANSI_GREEN, "▼ faster")
+        } else {
+            ("", "=")
+        };
+
+        if percent > threshold {
+            regressions += 1;
+        }
+
+        println!(
+            "Day {} {}: {} → {} {color}{percent:+.1}% {marker}{ANSI_RESET}",
+            change.day,
+            part_label(change.part),
+            format_nanos(change.before),
+            format_nanos(change.after),
+        );
+    }
+
+    regressions
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn format_nanos(nanos: f64) -> String {
+    format!("{:.1?}", std::time::Duration::from_nanos(nanos as u64))
+}
+
+/// Print how the runtime of each part of a day evolved across stored snapshots.
+pub fn handle_history(day: Day, statistic: Statistic) {
+    let entries = match history::read_day(Year::current(), day) {
+        Ok(entries) => entries,
+        Err(e) => {
+            eprintln!("Failed to read timings history: {e}");
+            process::exit(1);
+        }
+    };
+
+    println!("{ANSI_BOLD}Day {day} history ({statistic}){ANSI_RESET}");
+    println!("------");
+
+    if entries.is_empty() {
+        println!("No snapshots stored yet. Run `cargo time {day} --store` to record one.");
+        return;
+    }
+
+    let mut previous: [Option<f64>; 2] = [None, None];
+
+    for entry in &entries {
+        let parts = [1, 2].map(|part| {
+            let Some(nanos) = entry.timing.part_nanos(part, statistic) else {
+                return format!("Part {part}: -");
+            };
+
+            let change = match previous[usize::from(part) - 1].replace(nanos) {
+                Some(before) => {
+                    let change = PartChange {
+                        day,
+                        part,
+                        before,
+                        after: nanos,
+                    };
+                    format_percent(change.percent())
+                }
+                None => String::new(),
+            };
+
+            format!("Part {part}: {}{change}", format_nanos(nanos))
+        });
+
+        println!(
+            "{}  {:<16} {:<32} {}",
+            entry.date(),
+            entry.revision.as_deref().unwrap_or("-"),
+            parts[0],
+            parts[1]
+        );
+    }
+}
+
+fn format_percent(percent: f64) -> String {
+    format!(" ({percent:+.1}%)")
 }
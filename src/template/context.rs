@@ -0,0 +1,55 @@
+/// Puzzle parameters that differ between the example and the real input.
+///
+/// Some puzzles state parameters that depend on the input, e.g. "connect the 10 closest pairs" in
+/// the example but 1000 for the real input. Parts declared with `solution!(<day>, context)`
+/// receive a [`Context`] that tells them which input they are solving.
+use crate::template::{Day, Year, read_file, read_file_part};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Context {
+    pub is_example: bool,
+}
+
+impl Context {
+    /// The context of the examples in `data/<year>/examples`.
+    pub const EXAMPLE: Self = Self { is_example: true };
+    /// The context of the puzzle inputs in `data/<year>/inputs`.
+    pub const INPUT: Self = Self { is_example: false };
+
+    /// Returns `example` when solving the example, and `input` otherwise.
+    pub fn select<T>(&self, example: T, input: T) -> T {
+        if self.is_example { example } else { input }
+    }
+
+    /// Returns the data folder this context reads from.
+    pub fn folder(&self) -> &'static str {
+        self.select("examples", "inputs")
+    }
+
+    /// Reads the example or input of a day, see [`read_file`].
+    #[must_use]
+    pub fn read(&self, year: Year, day: Day) -> String {
+        read_file(self.folder(), year, day)
+    }
+
+    /// Reads the example or input of a day with a part suffix, see [`read_file_part`].
+    #[must_use]
+    pub fn read_part(&self, year: Year, day: Day, part: u8) -> String {
+        read_file_part(self.folder(), year, day, part)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Context;
+
+    #[test]
+    fn selects_parameters() {
+        assert_eq!(Context::EXAMPLE.select(10, 1000), 10);
+        assert_eq!(Context::INPUT.select(10, 1000), 1000);
+        assert_eq!(Context::EXAMPLE.folder(), "examples");
+        assert_eq!(Context::INPUT.folder(), "inputs");
+    }
+}
@@ -0,0 +1,373 @@
+/// Local cache of downloaded inputs and puzzles, so repeated commands don't hit the website.
+///
+/// Inputs never change, so a non-empty input file is always reused. Puzzle descriptions grow a
+/// second part once part one is solved, so they are only reused once complete, or while part one
+/// is unsolved and the description was fetched recently.
+///
+/// Fetch timestamps are recorded in `data/downloads.json`, which also enforces a minimum interval
+/// between requests across runs.
+use std::{
+    collections::HashMap,
+    fs,
+    io::Error,
+    path::{Path, PathBuf},
+    str::FromStr,
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::{Day, Year, answers::Answers, get_data_dir};
+
+/// Minimum time between two requests to the website.
+pub const MIN_REQUEST_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How long an incomplete puzzle description is reused if part one is not known to be solved.
+const PUZZLE_MAX_AGE: Duration = Duration::from_secs(15 * 60);
+
+/// Heading that marks the description of part two.
+const PART_TWO_HEADING: &str = "--- Part Two ---";
+
+/// Represents when the input and puzzle of a day were last fetched.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fetch {
+    pub year: Year,
+    pub day: Day,
+    /// Milliseconds since the unix epoch.
+    pub input: Option<u64>,
+    /// Milliseconds since the unix epoch.
+    pub puzzle: Option<u64>,
+}
+
+/// Represents the fetch log of all events.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Downloads {
+    /// Milliseconds since the unix epoch.
+    pub last_request: Option<u64>,
+    pub data: Vec<Fetch>,
+}
+
+impl Downloads {
+    /// Dehydrate the fetch log to its JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        fs::create_dir_all("data")?;
+        let mut file = fs::File::create(get_path())?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate the fetch log from its JSON file. If not present, returns an empty log.
+    pub fn read_from_file() -> Self {
+        fs::read_to_string(get_path())
+            .map_err(|x| x.to_string())
+            .and_then(Downloads::try_from)
+            .unwrap_or_default()
+    }
+
+    /// Blocks until [`MIN_REQUEST_INTERVAL`] has passed since the last request, then claims the
+    /// current time as the last request.
+    pub fn wait_for_slot(&mut self) {
+        // another process may have made a request since this log was read.
+        self.last_request = self.last_request.max(Self::read_from_file().last_request);
+
+        let wait = remaining_wait(self.last_request, now_millis());
+        if !wait.is_zero() {
+            println!(
+                "Waiting {:.1}s before the next request to the website...",
+                wait.as_secs_f64()
+            );
+            thread::sleep(wait);
+        }
+
+        self.last_request = Some(now_millis());
+        self.store();
+    }
+
+    /// Records that the input of a day was fetched just now.
+    pub fn record_input(&mut self, year: Year, day: Day) {
+        self.entry(year, day).input = Some(now_millis());
+        self.store();
+    }
+
+    /// Records that the puzzle of a day was fetched just now.
+    pub fn record_puzzle(&mut self, year: Year, day: Day) {
+        self.entry(year, day).puzzle = Some(now_millis());
+        self.store();
+    }
+
+    /// Returns `true` if the cached puzzle description of a day can be reused.
+    pub fn is_puzzle_fresh(&self, year: Year, day: Day) -> bool {
+        let Some(puzzle) = read_cached(&get_puzzle_path(year, day)) else {
+            return false;
+        };
+
+        if puzzle.contains(PART_TWO_HEADING) {
+            return true;
+        }
+
+        let fetched = self
+            .data
+            .iter()
+            .find(|f| f.year == year && f.day == day)
+            .and_then(|f| f.puzzle);
+
+        let Some(fetched) = fetched else {
+            return false;
+        };
+
+        // once part one is solved, the description has grown a second part.
+        Answers::read_from_file(year).get(day, 1).is_none()
+            && now_millis().saturating_sub(fetched) < duration_millis(PUZZLE_MAX_AGE)
+    }
+
+    fn entry(&mut self, year: Year, day: Day) -> &mut Fetch {
+        let index = match self
+            .data
+            .iter()
+            .position(|f| f.year == year && f.day == day)
+        {
+            Some(index) => index,
+            None => {
+                self.data.push(Fetch {
+                    year,
+                    day,
+                    input: None,
+                    puzzle: None,
+                });
+                self.data.sort_unstable_by_key(|f| (f.year, f.day));
+                self.data
+                    .iter()
+                    .position(|f| f.year == year && f.day == day)
+                    .unwrap()
+            }
+        };
+
+        &mut self.data[index]
+    }
+
+    fn store(&self) {
+        if let Err(e) = self.store_file() {
+            eprintln!("Failed to store download log: {e}");
+        }
+    }
+}
+
+/// Returns the cached input of a day, if it was downloaded already.
+pub fn cached_input(year: Year, day: Day) -> Option<String> {
+    read_cached(&get_input_path(year, day))
+}
+
+/// Returns the cached puzzle description of a day, if it was downloaded already.
+pub fn cached_puzzle(year: Year, day: Day) -> Option<String> {
+    read_cached(&get_puzzle_path(year, day))
+}
+
+pub fn get_input_path(year: Year, day: Day) -> PathBuf {
+    get_data_dir(year, "inputs").join(format!("{day}.txt"))
+}
+
+pub fn get_puzzle_path(year: Year, day: Day) -> PathBuf {
+    get_data_dir(year, "puzzles").join(format!("{day}.md"))
+}
+
+fn get_path() -> PathBuf {
+    Path::new("data").join("downloads.json")
+}
+
+/// Reads a cached file. Empty files, e.g. inputs created by `scaffold`, don't count.
+fn read_cached(path: &Path) -> Option<String> {
+    fs::read_to_string(path)
+        .ok()
+        .filter(|contents| !contents.trim().is_empty())
+}
+
+fn remaining_wait(last_request: Option<u64>, now: u64) -> Duration {
+    let Some(last_request) = last_request else {
+        return Duration::ZERO;
+    };
+
+    let next_request = last_request + duration_millis(MIN_REQUEST_INTERVAL);
+    Duration::from_millis(next_request.saturating_sub(now))
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn duration_millis(duration: Duration) -> u64 {
+    duration.as_millis() as u64
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, duration_millis)
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Downloads> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: Downloads) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "last_request".into(),
+            match value.last_request {
+                Some(x) => JsonValue::Number(x as f64),
+                None => JsonValue::Null,
+            },
+        );
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Downloads {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let json_data = json
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Downloads {
+            last_request: get_timestamp(json, "last_request")?,
+            data: json_data
+                .iter()
+                .map(Fetch::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Fetch> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &Fetch) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("year".into(), JsonValue::String(value.year.to_string()));
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+
+        for (key, timestamp) in [("input", value.input), ("puzzle", value.puzzle)] {
+            map.insert(
+                key.into(),
+                match timestamp {
+                    Some(x) => JsonValue::Number(x as f64),
+                    None => JsonValue::Null,
+                },
+            );
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Fetch {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected fetch to be a JSON object.")?;
+
+        let year = json
+            .get("year")
+            .and_then(|v| v.get::<String>())
+            .and_then(|year| Year::from_str(year).ok())
+            .ok_or("Expected fetch.year to be a Year struct.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected fetch.day to be a Day struct.")?;
+
+        Ok(Fetch {
+            year,
+            day,
+            input: get_timestamp(json, "input")?,
+            puzzle: get_timestamp(json, "puzzle")?,
+        })
+    }
+}
+
+/// Reads an optional timestamp. A missing key is treated like `null`.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn get_timestamp(json: &HashMap<String, JsonValue>, key: &str) -> Result<Option<u64>, String> {
+    match json.get(key) {
+        None | Some(JsonValue::Null) => Ok(None),
+        Some(JsonValue::Number(x)) => Ok(Some(*x as u64)),
+        Some(_) => Err(format!("Expected {key} to be null or a number.")),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use tinyjson::JsonValue;
+
+    use super::{Downloads, Fetch, MIN_REQUEST_INTERVAL, remaining_wait};
+    use crate::{day, template::Year};
+
+    #[test]
+    fn roundtrips_downloads() {
+        let downloads = Downloads {
+            last_request: Some(1_733_650_000_123),
+            data: vec![
+                Fetch {
+                    year: Year::new(2024).unwrap(),
+                    day: day!(1),
+                    input: Some(1_733_000_000_000),
+                    puzzle: None,
+                },
+                Fetch {
+                    year: Year::new(2025).unwrap(),
+                    day: day!(3),
+                    input: Some(1_733_650_000_000),
+                    puzzle: Some(1_733_650_000_123),
+                },
+            ],
+        };
+
+        let json = JsonValue::from(downloads.clone()).stringify().unwrap();
+        assert_eq!(Downloads::try_from(json).unwrap(), downloads);
+    }
+
+    #[test]
+    fn treats_missing_timestamps_as_null() {
+        let json = r#"{ "data": [{ "year": "2025", "day": "01" }] }"#;
+        let downloads = Downloads::try_from(json.to_string()).unwrap();
+
+        assert_eq!(downloads.last_request, None);
+        assert_eq!(downloads.data[0].input, None);
+        assert_eq!(downloads.data[0].puzzle, None);
+    }
+
+    #[test]
+    fn waits_for_min_interval() {
+        assert_eq!(remaining_wait(None, 10_000), Duration::ZERO);
+        assert_eq!(
+            remaining_wait(Some(10_000), 12_000),
+            MIN_REQUEST_INTERVAL - Duration::from_secs(2)
+        );
+        assert_eq!(remaining_wait(Some(10_000), 60_000), Duration::ZERO);
+    }
+}
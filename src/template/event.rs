@@ -0,0 +1,22 @@
+// Rules of the event that `build.rs` needs as well. Both `year.rs` and `build.rs` `include!`
+// this file, since the build script cannot depend on the library.
+
+/// The year of the event if `AOC_YEAR` is not set.
+const DEFAULT_YEAR: u16 = 2025;
+
+/// Returns the year configured with `AOC_YEAR`, or [`DEFAULT_YEAR`] if it is not set.
+const fn configured_year(aoc_year: Option<&str>) -> u16 {
+    match aoc_year {
+        Some(year) => match u16::from_str_radix(year, 10) {
+            Ok(year) => year,
+            Err(_) => panic!("AOC_YEAR is not a number"),
+        },
+        None => DEFAULT_YEAR,
+    }
+}
+
+/// Returns the last day of the event in `year`. Starting with 2025, the event runs for 12 days
+/// instead of 25.
+const fn last_day(year: u16) -> u8 {
+    if year >= 2025 { 12 } else { 25 }
+}
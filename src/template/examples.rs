@@ -0,0 +1,189 @@
+/// Extracts example inputs and answers from the Markdown of a puzzle description.
+///
+/// Puzzles introduce their example in a code block after a paragraph mentioning "example", and
+/// state the example answer as emphasized code (`` *`42`* ``) near the end of each part.
+/// These are heuristics: they fit most puzzles, but the extracted values should be double-checked.
+use std::mem;
+
+/// Heading that starts the description of part two.
+const PART_TWO_HEADING: &str = "--- Part Two ---";
+
+/// The example of one part of a puzzle.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Example {
+    pub input: Option<String>,
+    pub answer: Option<String>,
+}
+
+/// The examples of both parts of a puzzle. Part two may not be unlocked yet.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Examples {
+    pub part_1: Example,
+    pub part_2: Example,
+}
+
+impl Examples {
+    /// Returns the input of part two if it differs from the input of part one.
+    pub fn separate_part_2_input(&self) -> Option<&str> {
+        match (&self.part_1.input, &self.part_2.input) {
+            (Some(part_1), Some(part_2)) if part_1 == part_2 => None,
+            (_, part_2) => part_2.as_deref(),
+        }
+    }
+}
+
+pub fn extract(markdown: &str) -> Examples {
+    let (part_1, part_2) = match markdown.find(PART_TWO_HEADING) {
+        Some(index) => markdown.split_at(index),
+        None => (markdown, ""),
+    };
+
+    Examples {
+        part_1: Example {
+            input: find_example_block(part_1).or_else(|| first_block(part_1)),
+            answer: find_answer(part_1),
+        },
+        // part two usually reuses the example of part one, and only shows it again if it changed.
+        part_2: Example {
+            input: find_example_block(part_2),
+            answer: find_answer(part_2),
+        },
+    }
+}
+
+/// Returns the code blocks of a section, together with the paragraph preceding each.
+fn code_blocks(markdown: &str) -> Vec<(String, String)> {
+    let mut blocks = vec![];
+    // text since the last blank line, and the last complete paragraph.
+    let mut paragraph = String::new();
+    let mut previous = String::new();
+    let mut code: Option<String> = None;
+
+    for line in markdown.lines() {
+        let is_fence = line.trim_start().starts_with("```");
+
+        if let Some(block) = &mut code {
+            if is_fence {
+                blocks.push((mem::take(&mut previous), mem::take(block)));
+                code = None;
+            } else {
+                block.push_str(line);
+                block.push('\n');
+            }
+        } else if is_fence || line.trim().is_empty() {
+            if !paragraph.is_empty() {
+                previous = mem::take(&mut paragraph);
+            }
+            if is_fence {
+                code = Some(String::new());
+            }
+        } else {
+            if !paragraph.is_empty() {
+                paragraph.push(' ');
+            }
+            paragraph.push_str(line.trim());
+        }
+    }
+
+    blocks
+}
+
+fn find_example_block(markdown: &str) -> Option<String> {
+    code_blocks(markdown)
+        .into_iter()
+        .find(|(paragraph, _)| paragraph.to_lowercase().contains("example"))
+        .map(|(_, block)| block)
+}
+
+fn first_block(markdown: &str) -> Option<String> {
+    code_blocks(markdown).into_iter().next().map(|(_, b)| b)
+}
+
+/// Returns the last emphasized code span of a section, which usually is the example answer.
+fn find_answer(markdown: &str) -> Option<String> {
+    let mut answer = None;
+    let mut rest = markdown;
+
+    while let Some(start) = rest.find("*`") {
+        let value = &rest[start + 2..];
+        let Some(end) = value.find("`*") else {
+            break;
+        };
+        if !value[..end].contains('\n') {
+            answer = Some(value[..end].to_string());
+        }
+        rest = &value[end + 2..];
+    }
+
+    answer
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Example, Examples, extract};
+
+    const PART_ONE: &str = "## --- Day 3: Lobby ---
+
+There are batteries.
+
+```
+987
+```
+
+For example:
+
+```
+987654321111111
+811111111111119
+```
+
+In `987654321111111`, you can make *`98`*. The total is `98` + `89` = *`187`*.
+
+Find the *maximum* joltage.
+";
+
+    #[test]
+    fn extracts_part_one() {
+        assert_eq!(
+            extract(PART_ONE),
+            Examples {
+                part_1: Example {
+                    input: Some("987654321111111\n811111111111119\n".into()),
+                    answer: Some("187".into()),
+                },
+                part_2: Example::default(),
+            }
+        );
+    }
+
+    #[test]
+    fn extracts_part_two_answers() {
+        let markdown = format!(
+            "{PART_ONE}\n## --- Part Two ---\n\nNow use twelve. In the example above, the total is *`3121910778619`*.\n"
+        );
+        let examples = extract(&markdown);
+
+        assert_eq!(examples.part_2.answer.as_deref(), Some("3121910778619"));
+        assert_eq!(examples.part_2.input, None);
+        assert_eq!(examples.separate_part_2_input(), None);
+    }
+
+    #[test]
+    fn extracts_separate_part_two_examples() {
+        let markdown = format!(
+            "{PART_ONE}\n## --- Part Two ---\n\nHere is a different example:\n\n```\n123\n```\n\nThe answer is *`6`*.\n"
+        );
+        let examples = extract(&markdown);
+
+        assert_eq!(examples.separate_part_2_input(), Some("123\n"));
+        assert_eq!(examples.part_2.answer.as_deref(), Some("6"));
+    }
+
+    #[test]
+    fn falls_back_to_first_block() {
+        let examples = extract("Consider:\n\n```\n1 2\n```\n\nThe answer is *`3`*.\n");
+        assert_eq!(examples.part_1.input.as_deref(), Some("1 2\n"));
+    }
+}
@@ -0,0 +1,229 @@
+/// Append-only log of benchmark snapshots, one JSON line per day and run.
+use std::{
+    collections::HashMap,
+    fs,
+    io::{self, Write},
+    path::PathBuf,
+    process::Command,
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::{
+    Day, Year, get_data_dir,
+    timings::{Timing, Timings},
+};
+
+/// Represents the timing of a day at a point in time.
+#[derive(Clone, Debug)]
+pub struct HistoryEntry {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    /// Output of `git describe --always --dirty`, if available.
+    pub revision: Option<String>,
+    pub timing: Timing,
+}
+
+impl HistoryEntry {
+    /// Formats the timestamp as `YYYY-MM-DD HH:MM` (UTC).
+    pub fn date(&self) -> String {
+        let days = self.timestamp / 86_400;
+        let secs = self.timestamp % 86_400;
+        let (year, month, day) = civil_from_days(days);
+        format!(
+            "{year:04}-{month:02}-{day:02} {:02}:{:02}",
+            secs / 3600,
+            secs % 3600 / 60
+        )
+    }
+}
+
+/// Append a snapshot of `timings` to the history file of an event, tagged with the current time
+/// and revision.
+pub fn append(year: Year, timings: &Timings) -> Result<(), io::Error> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let revision = get_revision();
+
+    fs::create_dir_all(get_data_dir(year, ""))?;
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(get_path(year))?;
+
+    for timing in &timings.data {
+        let entry = HistoryEntry {
+            timestamp,
+            revision: revision.clone(),
+            timing: timing.clone(),
+        };
+        let line = JsonValue::from(&entry)
+            .stringify()
+            .map_err(|e| io::Error::other(e.to_string()))?;
+        writeln!(file, "{line}")?;
+    }
+
+    Ok(())
+}
+
+/// Read all snapshots of a day, oldest first. If the file is not present, returns no entries.
+pub fn read_day(year: Year, day: Day) -> Result<Vec<HistoryEntry>, String> {
+    let contents = match fs::read_to_string(get_path(year)) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e.to_string()),
+    };
+
+    let mut entries = parse_lines(&contents)?;
+    entries.retain(|e| e.timing.day == day);
+    entries.sort_by_key(|e| e.timestamp);
+    Ok(entries)
+}
+
+fn get_path(year: Year) -> PathBuf {
+    get_data_dir(year, "").join("timings-history.jsonl")
+}
+
+fn parse_lines(contents: &str) -> Result<Vec<HistoryEntry>, String> {
+    contents
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| {
+            let json = JsonValue::from_str(l).or(Err("history line is not valid JSON."))?;
+            HistoryEntry::try_from(&json)
+        })
+        .collect()
+}
+
+fn get_revision() -> Option<String> {
+    let output = Command::new("git")
+        .args(["describe", "--always", "--dirty"])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let revision = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!revision.is_empty()).then_some(revision)
+}
+
+/// Converts days since the unix epoch to a `(year, month, day)` date.
+/// See: https://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: u64) -> (u64, u64, u64) {
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+    (year, month, day)
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&HistoryEntry> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &HistoryEntry) -> Self {
+        let mut json = JsonValue::from(&value.timing);
+
+        if let JsonValue::Object(map) = &mut json {
+            map.insert(
+                "timestamp".into(),
+                JsonValue::Number(value.timestamp as f64),
+            );
+            map.insert(
+                "revision".into(),
+                match &value.revision {
+                    Some(x) => JsonValue::String(x.clone()),
+                    None => JsonValue::Null,
+                },
+            );
+        }
+
+        json
+    }
+}
+
+impl TryFrom<&JsonValue> for HistoryEntry {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected history entry to be a JSON object.")?;
+
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected entry.timestamp to be a number.")?;
+
+        let revision = json
+            .get("revision")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected entry.revision to be null or string.")?;
+
+        Ok(HistoryEntry {
+            timestamp: timestamp as u64,
+            revision: revision.cloned(),
+            timing: Timing::try_from(value)?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use tinyjson::JsonValue;
+
+    use super::{HistoryEntry, civil_from_days, parse_lines};
+    use crate::{day, template::timings::Timing};
+
+    fn get_mock_entry(timestamp: u64) -> HistoryEntry {
+        HistoryEntry {
+            timestamp,
+            revision: Some("ebf2e2c-dirty".into()),
+            timing: Timing {
+                part_1: Some("10ms".into()),
+                total_nanos: 1e+7,
+                ..Timing::new(day!(8))
+            },
+        }
+    }
+
+    #[test]
+    fn roundtrips_entries() {
+        let line = JsonValue::from(&get_mock_entry(1_765_200_000))
+            .stringify()
+            .unwrap();
+        let entries = parse_lines(&format!("{line}\n\n{line}\n")).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].timestamp, 1_765_200_000);
+        assert_eq!(entries[0].revision.as_deref(), Some("ebf2e2c-dirty"));
+        assert_eq!(entries[0].timing.day, day!(8));
+        assert_eq!(entries[0].timing.part_1.as_deref(), Some("10ms"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_entries_without_timestamp() {
+        parse_lines(r#"{ "day": "01", "part_1": null, "part_2": null, "total_nanos": 0 }"#)
+            .unwrap();
+    }
+
+    #[test]
+    fn formats_dates() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(20_430), (2025, 12, 8));
+        assert_eq!(get_mock_entry(1_765_200_000).date(), "2025-12-08 13:20");
+    }
+}
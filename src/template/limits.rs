@@ -0,0 +1,256 @@
+/// Wall-clock and memory limits for solution binaries.
+///
+/// The timeout covers the whole run of a solution, unless it is benched: benchmarks run each part
+/// for about a second, so the parent passes the timeout to the solution via [`TIMEOUT_ENV`]
+/// instead, and it only applies to the first run of each part (and of the parsing).
+use std::{
+    collections::HashMap,
+    env,
+    fmt::Display,
+    fs, io,
+    process::Child,
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
+    time::{Duration, Instant},
+};
+
+use tinyjson::JsonValue;
+
+/// Interval at which running children are checked against their limits.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Limits that a solution run is stopped at.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Limits {
+    pub timeout: Option<Duration>,
+    /// Maximum resident set size in megabytes. Only enforced on Linux.
+    pub max_rss_mb: Option<u64>,
+}
+
+/// Why a solution run was stopped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RunFailure {
+    TimedOut,
+    OutOfMemory,
+}
+
+impl Display for RunFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            RunFailure::TimedOut => "timed out",
+            RunFailure::OutOfMemory => "out of memory",
+        })
+    }
+}
+
+impl RunFailure {
+    /// Short label used in tables and JSON.
+    pub fn label(self) -> &'static str {
+        match self {
+            RunFailure::TimedOut => "timeout",
+            RunFailure::OutOfMemory => "oom",
+        }
+    }
+
+    pub fn from_label(s: &str) -> Option<Self> {
+        match s {
+            "timeout" => Some(RunFailure::TimedOut),
+            "oom" => Some(RunFailure::OutOfMemory),
+            _ => None,
+        }
+    }
+}
+
+impl Limits {
+    pub fn is_empty(&self) -> bool {
+        self.timeout.is_none() && self.max_rss_mb.is_none()
+    }
+
+    /// Checks a running child against the limits and kills it if one is exceeded.
+    pub fn enforce(&self, child: &mut Child, started: Instant) -> Option<RunFailure> {
+        let failure = if self.timeout.is_some_and(|t| started.elapsed() > t) {
+            Some(RunFailure::TimedOut)
+        } else if self
+            .max_rss_mb
+            .zip(get_rss_mb(child.id()))
+            .is_some_and(|(max, rss)| rss > max)
+        {
+            Some(RunFailure::OutOfMemory)
+        } else {
+            None
+        };
+
+        if failure.is_some() {
+            let _ = child.kill();
+        }
+
+        failure
+    }
+
+    /// Waits for a child to exit while enforcing the limits.
+    pub fn wait(&self, child: &mut Child) -> Result<Option<RunFailure>, io::Error> {
+        let started = Instant::now();
+
+        loop {
+            if child.try_wait()?.is_some() {
+                return Ok(None);
+            }
+
+            if let Some(failure) = self.enforce(child, started) {
+                child.wait()?;
+                return Ok(Some(failure));
+            }
+
+            std::thread::sleep(POLL_INTERVAL);
+        }
+    }
+}
+
+/// Passes the timeout in seconds to solution binaries that are benched.
+pub const TIMEOUT_ENV: &str = "AOC_TIMEOUT";
+
+/// Reads the timeout passed by the parent process, see [`TIMEOUT_ENV`].
+pub fn timeout_from_env() -> Option<Duration> {
+    env::var(TIMEOUT_ENV)
+        .ok()
+        .and_then(|x| x.parse().ok())
+        .map(Duration::from_secs_f64)
+}
+
+/// Runs `f`, calling `on_timeout` on a separate thread if it does not return within `timeout`.
+/// `on_timeout` is expected to exit the process.
+pub fn with_timeout<T>(
+    timeout: Option<Duration>,
+    f: impl FnOnce() -> T,
+    on_timeout: impl FnOnce() + Send + 'static,
+) -> T {
+    let Some(timeout) = timeout else {
+        return f();
+    };
+
+    let (tx, rx) = mpsc::channel::<()>();
+    let watchdog = thread::spawn(move || {
+        if rx.recv_timeout(timeout) == Err(RecvTimeoutError::Timeout) {
+            on_timeout();
+        }
+    });
+
+    let result = f();
+    drop(tx);
+    let _ = watchdog.join();
+    result
+}
+
+/// Reads the resident set size of a process from procfs.
+fn get_rss_mb(pid: u32) -> Option<u64> {
+    if !cfg!(target_os = "linux") {
+        return None;
+    }
+
+    let status = fs::read_to_string(format!("/proc/{pid}/status")).ok()?;
+    let kilobytes: u64 = status
+        .lines()
+        .find_map(|l| l.strip_prefix("VmRSS:"))?
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse()
+        .ok()?;
+
+    Some(kilobytes / 1024)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Converts an optional run failure to JSON, using `null` for [`None`].
+pub fn failure_to_json(failure: Option<RunFailure>) -> JsonValue {
+    match failure {
+        Some(x) => JsonValue::String(x.label().into()),
+        None => JsonValue::Null,
+    }
+}
+
+/// Reads an optional run failure. A missing key is treated like `null`.
+pub fn failure_from_json(
+    json: &HashMap<String, JsonValue>,
+    key: &str,
+) -> Result<Option<RunFailure>, String> {
+    match json.get(key) {
+        None => Ok(None),
+        Some(v) if v.is_null() => Ok(None),
+        Some(v) => v
+            .get::<String>()
+            .and_then(|s| RunFailure::from_label(s))
+            .map(Some)
+            .ok_or(format!(
+                "Expected {key} to be null, \"timeout\" or \"oom\"."
+            )),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        process::{Command, Stdio},
+        time::Duration,
+    };
+
+    use super::{Limits, RunFailure, with_timeout};
+
+    #[test]
+    fn parses_failure_labels() {
+        for failure in [RunFailure::TimedOut, RunFailure::OutOfMemory] {
+            assert_eq!(RunFailure::from_label(failure.label()), Some(failure));
+        }
+        assert_eq!(RunFailure::from_label("other"), None);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn kills_children_after_timeout() {
+        let mut child = Command::new("sleep")
+            .arg("5")
+            .stdout(Stdio::null())
+            .spawn()
+            .unwrap();
+        let limits = Limits {
+            timeout: Some(Duration::from_millis(50)),
+            max_rss_mb: None,
+        };
+        assert_eq!(limits.wait(&mut child).unwrap(), Some(RunFailure::TimedOut));
+    }
+
+    #[test]
+    fn calls_back_after_timeout() {
+        let (tx, rx) = std::sync::mpsc::channel();
+        let result = with_timeout(
+            Some(Duration::from_millis(10)),
+            || {
+                std::thread::sleep(Duration::from_millis(200));
+                42
+            },
+            move || tx.send(()).unwrap(),
+        );
+        assert_eq!(result, 42);
+        assert!(rx.try_recv().is_ok());
+    }
+
+    #[test]
+    fn returns_within_timeout() {
+        let result = with_timeout(Some(Duration::from_secs(5)), || 42, || panic!("timed out"));
+        assert_eq!(result, 42);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn waits_for_children_within_limits() {
+        let mut child = Command::new("true").spawn().unwrap();
+        let limits = Limits {
+            timeout: Some(Duration::from_secs(5)),
+            max_rss_mb: Some(1024),
+        };
+        assert_eq!(limits.wait(&mut child).unwrap(), None);
+    }
+}
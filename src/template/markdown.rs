@@ -0,0 +1,297 @@
+// Converts the HTML of puzzle descriptions to Markdown.
+//
+// Only the handful of tags used on puzzle pages are translated, all other tags are dropped and
+// their text is kept.
+
+/// Extracts the descriptions (`<article class="day-desc">`) of a puzzle page as Markdown.
+/// Returns [`None`] if the page does not contain any.
+pub fn extract_descriptions(html: &str) -> Option<String> {
+    let articles = extract_articles(html, "<article class=\"day-desc\"");
+    (!articles.is_empty()).then(|| {
+        articles
+            .iter()
+            .map(|a| html_to_markdown(a))
+            .collect::<Vec<_>>()
+            .join("\n")
+    })
+}
+
+/// Extracts the first `<article>` of a page as Markdown, e.g. the response to a submission.
+pub fn extract_message(html: &str) -> Option<String> {
+    extract_articles(html, "<article")
+        .first()
+        .map(|a| html_to_markdown(a))
+}
+
+fn extract_articles<'a>(html: &'a str, opening: &str) -> Vec<&'a str> {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find(opening) {
+        let Some(end) = rest[start..].find("</article>") else {
+            break;
+        };
+        articles.push(&rest[start..start + end]);
+        rest = &rest[start + end..];
+    }
+
+    articles
+}
+
+/// Converts a fragment of HTML to Markdown.
+pub fn html_to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut rest = html;
+    let mut in_pre = false;
+    let mut code_depth = 0_u32;
+    // `<code><em>` marks example answers, keep it as an emphasized code span.
+    let mut em_wraps_code = false;
+    let mut links: Vec<Option<String>> = vec![];
+
+    while !rest.is_empty() {
+        if let Some(tag) = rest.strip_prefix('<') {
+            let Some(end) = tag.find('>') else {
+                break;
+            };
+            let (tag, remaining) = (&tag[..end], &tag[end + 1..]);
+            rest = remaining;
+
+            let is_closing = tag.starts_with('/');
+            let name = tag
+                .trim_start_matches('/')
+                .split(|c: char| c.is_whitespace() || c == '/')
+                .next()
+                .unwrap_or_default()
+                .to_ascii_lowercase();
+
+            match (name.as_str(), is_closing) {
+                ("h2", false) => {
+                    start_block(&mut out);
+                    out.push_str("## ");
+                }
+                ("h2" | "p" | "ul", true) => out.push_str("\n\n"),
+                ("p" | "ul", false) => start_block(&mut out),
+                ("li", false) => {
+                    start_line(&mut out);
+                    out.push_str("- ");
+                }
+                ("li", true) => out.push('\n'),
+                ("br", _) => out.push('\n'),
+                ("pre", false) => {
+                    start_block(&mut out);
+                    out.push_str("```\n");
+                    in_pre = true;
+                }
+                ("pre", true) => {
+                    start_line(&mut out);
+                    out.push_str("```\n\n");
+                    in_pre = false;
+                }
+                ("code", false) if !in_pre => {
+                    code_depth += 1;
+                    out.push('`');
+                }
+                ("code", true) if !in_pre => {
+                    code_depth = code_depth.saturating_sub(1);
+                    out.push('`');
+                    if em_wraps_code {
+                        out.push('*');
+                        em_wraps_code = false;
+                    }
+                }
+                ("em", false) if !in_pre && code_depth > 0 && out.ends_with('`') => {
+                    out.pop();
+                    out.push_str("*`");
+                    em_wraps_code = true;
+                }
+                ("em", _) if !in_pre && code_depth == 0 => out.push('*'),
+                ("a", false) => {
+                    let href = get_attribute(tag, "href");
+                    if href.is_some() {
+                        out.push('[');
+                    }
+                    links.push(href);
+                }
+                ("a", true) => {
+                    if let Some(href) = links.pop().flatten() {
+                        out.push_str(&format!("]({href})"));
+                    }
+                }
+                _ => {}
+            }
+        } else {
+            let end = rest.find('<').unwrap_or(rest.len());
+            let text = decode_entities(&rest[..end]);
+            rest = &rest[end..];
+
+            if in_pre {
+                out.push_str(&text);
+            } else {
+                push_collapsed(&mut out, &text);
+            }
+        }
+    }
+
+    let mut markdown = out
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    while markdown.contains("\n\n\n") {
+        markdown = markdown.replace("\n\n\n", "\n\n");
+    }
+
+    format!("{}\n", markdown.trim())
+}
+
+/// Pushes text with whitespace collapsed to single spaces, like a browser would render it.
+fn push_collapsed(out: &mut String, text: &str) {
+    let mut words = text.split_whitespace().peekable();
+
+    if text.starts_with(char::is_whitespace) && !out.is_empty() && !out.ends_with(['\n', ' ']) {
+        out.push(' ');
+    }
+
+    while let Some(word) = words.next() {
+        out.push_str(word);
+        if words.peek().is_some() || text.ends_with(char::is_whitespace) {
+            out.push(' ');
+        }
+    }
+}
+
+fn start_line(out: &mut String) {
+    if !out.is_empty() && !out.ends_with('\n') {
+        out.push('\n');
+    }
+}
+
+fn start_block(out: &mut String) {
+    start_line(out);
+    if !out.is_empty() && !out.ends_with("\n\n") {
+        out.push('\n');
+    }
+}
+
+fn get_attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = start + tag[start..].find('"')?;
+    Some(decode_entities(&tag[start..end]))
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let decoded = rest.find(';').and_then(|end| {
+            let entity = &rest[1..end];
+            let c = match entity {
+                "lt" => '<',
+                "gt" => '>',
+                "amp" => '&',
+                "quot" => '"',
+                "apos" => '\'',
+                "nbsp" => ' ',
+                _ => {
+                    let code = entity.strip_prefix('#')?;
+                    let code = match code.strip_prefix(['x', 'X']) {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => code.parse().ok()?,
+                    };
+                    char::from_u32(code)?
+                }
+            };
+            Some((c, end))
+        });
+
+        match decoded {
+            Some((c, end)) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{decode_entities, extract_descriptions, extract_message, html_to_markdown};
+
+    #[test]
+    fn converts_descriptions() {
+        let html = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Secret Entrance ---</h2><p>The dial starts at <code>50</code>. Count <em>every</em> click,
+see <a href="/2025/about" target="_blank">about</a>.</p>
+<pre><code>L68
+L30
+</code></pre>
+<ul>
+<li>Turning <code>L</code> moves <code><em>left</em></code>.</li>
+<li>Turning <code>R</code> moves right.</li>
+</ul>
+</article>
+<p>Your puzzle answer was <code>3</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Now it's &lt;harder&gt; &amp; longer.</p></article>
+</main>"#;
+
+        assert_eq!(
+            extract_descriptions(html).unwrap(),
+            [
+                "## --- Day 1: Secret Entrance ---",
+                "",
+                "The dial starts at `50`. Count *every* click, see [about](/2025/about).",
+                "",
+                "```",
+                "L68",
+                "L30",
+                "```",
+                "",
+                "- Turning `L` moves *`left`*.",
+                "- Turning `R` moves right.",
+                "",
+                "## --- Part Two ---",
+                "",
+                "Now it's <harder> & longer.",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn handles_pages_without_descriptions() {
+        assert_eq!(extract_descriptions("<p>Please log in.</p>"), None);
+    }
+
+    #[test]
+    fn extracts_messages() {
+        let html = "<main><article><p>That's the right answer!  You are <em>one gold star</em> closer.</p></article></main>";
+        assert_eq!(
+            extract_message(html).unwrap(),
+            "That's the right answer! You are *one gold star* closer.\n"
+        );
+    }
+
+    #[test]
+    fn decodes_entities() {
+        assert_eq!(
+            decode_entities("a &lt;b&gt; &#39;c&#x27; &unknown; &"),
+            "a <b> 'c' &unknown; &"
+        );
+        assert_eq!(html_to_markdown("<p>x&nbsp;y</p>"), "x y\n");
+    }
+}
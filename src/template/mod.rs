@@ -1,57 +1,167 @@
-use std::{env, fs};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
 
 pub mod aoc_cli;
+pub mod client;
 pub mod commands;
+pub mod registry;
+pub mod report;
 pub mod runner;
 
+pub use context::Context;
 pub use day::*;
+pub use limits::Limits;
+pub use runner::{Parse, PartResult};
+pub use stats::Statistic;
+pub use year::*;
 
+mod answers;
+mod context;
 mod day;
+mod downloads;
+mod examples;
+mod history;
+mod limits;
+mod markdown;
+mod output;
 mod readme_benchmarks;
 mod run_multi;
+mod stats;
+mod templates;
 mod timings;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
+pub const ANSI_RED: &str = "\x1b[31m";
+pub const ANSI_GREEN: &str = "\x1b[32m";
+
+/// Returns the path of a data folder of an event, e.g. `data/2025/inputs`.
+#[must_use]
+pub fn get_data_dir(year: Year, folder: &str) -> PathBuf {
+    Path::new("data").join(year.to_string()).join(folder)
+}
 
 /// Helper function that reads a text file to a string.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn read_file(folder: &str, year: Year, day: Day) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let filepath = cwd
+        .join(get_data_dir(year, folder))
+        .join(format!("{day}.txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn read_file_part(folder: &str, year: Year, day: Day, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
-        .join("data")
-        .join(folder)
+        .join(get_data_dir(year, folder))
         .join(format!("{day}-{part}.txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Creates the constants `YEAR` and `DAY` and sets up the input and runner for each part.
+///
+/// The year is taken from the name of the solution binary, e.g. `2025_01`.
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+///
+/// With `parse = <function>`, the input is parsed once by the function and both parts receive a
+/// reference to the result instead of the raw input, e.g. `solution!(1, parse = Input::parse)`
+/// with `fn part_one(input: &Input)`. The function returns a `Result`, the parts are skipped if
+/// parsing fails. The parse time is reported separately from the parts.
+///
+/// With `context`, the parts receive a [`Context`] as second argument, which tells them if they
+/// are solving the example or the real input, e.g. `solution!(8, context)` with
+/// `fn part_one(input: &str, context: &Context)`. Options can be combined, e.g.
+/// `solution!(8, 1, context, parse = Input::parse)`.
+///
+/// Besides `main`, this defines a `run` function that the `advent_of_code` binary uses to run the
+/// solution in-process.
 #[macro_export]
 macro_rules! solution {
+    (@options $day:expr, $parts:tt, $parse:tt, $context:tt, context $(, $($rest:tt)*)?) => {
+        $crate::solution!(@options $day, $parts, $parse, [context], $($($rest)*)?);
+    };
+    (@options $day:expr, $parts:tt, $parse:tt, $context:tt, parse = $p:expr $(, $($rest:tt)*)?) => {
+        $crate::solution!(@options $day, $parts, [$p], $context, $($($rest)*)?);
+    };
+    (@options $day:expr, { $($part:tt)* }, [], $context:tt, ) => {
+        $crate::solution!(@impl $day, $context, $($part)*);
+    };
+    (@options $day:expr, { $($part:tt)* }, [$p:expr], $context:tt, ) => {
+        $crate::solution!(@parsed $day, $p, $context, $($part)*);
+    };
+
+    (@part [], $func:expr, $context:ident) => {
+        $func
+    };
+    (@part [context], $func:expr, $context:ident) => {
+        |input| $func(input, &$context)
+    };
+
+    (@impl $day:expr, $context:tt, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@common $day);
+
+        /// Runs the solution against the puzzle input.
+        pub fn run(
+            options: &$crate::template::runner::RunOptions,
+        ) -> Vec<$crate::template::report::PartReport> {
+            use $crate::template::runner::*;
+            let context = $crate::template::Context::INPUT;
+            let input = context.read(YEAR, DAY);
+            vec![$(
+                run_part($crate::solution!(@part $context, $func, context), &input, YEAR, DAY, $part, options),
+            )*]
+        }
+    };
+
+    (@parsed $day:expr, $parse:expr, $context:tt, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@common $day);
+
+        /// Parses the puzzle input once and runs the solution against it.
+        pub fn run(
+            options: &$crate::template::runner::RunOptions,
+        ) -> Vec<$crate::template::report::PartReport> {
+            use $crate::template::runner::*;
+            let context = $crate::template::Context::INPUT;
+            let input = context.read(YEAR, DAY);
+            let (parsed, parse_report) = run_parse($parse, &input, DAY, options);
+            let mut reports = vec![parse_report];
+            if let Ok(parsed) = parsed {
+                $(
+                    reports.push(run_part($crate::solution!(@part $context, $func, context), &parsed, YEAR, DAY, $part, options));
+                )*
+            }
+            reports
+        }
+    };
+
     ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+        $crate::solution!(@options $day, { [part_one, 1] [part_two, 2] }, [], [], );
     };
-    ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+    ($day:expr, 1 $(, $($rest:tt)*)?) => {
+        $crate::solution!(@options $day, { [part_one, 1] }, [], [], $($($rest)*)?);
     };
-    ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+    ($day:expr, 2 $(, $($rest:tt)*)?) => {
+        $crate::solution!(@options $day, { [part_two, 2] }, [], [], $($($rest)*)?);
     };
+    ($day:expr, $($rest:tt)*) => {
+        $crate::solution!(@options $day, { [part_one, 1] [part_two, 2] }, [], [], $($rest)*);
+    };
+
+    (@common $day:expr) => {
+        /// The year of the event.
+        const YEAR: $crate::template::Year =
+            $crate::template::Year::from_bin_name(option_env!("CARGO_BIN_NAME"));
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
@@ -60,9 +170,7 @@ macro_rules! solution {
         static ALLOC: dhat::Alloc = dhat::Alloc;
 
         fn main() {
-            use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            run(&$crate::template::runner::RunOptions::from_args());
         }
     };
 }
@@ -0,0 +1,182 @@
+/// Output of the runner that can be buffered per thread.
+///
+/// When days run concurrently, their output is captured and printed in day order afterwards.
+/// Outside of [`capture`], output goes straight to stdout and stderr.
+use std::{
+    cell::RefCell,
+    fmt::{Arguments, Write as _},
+    io::{Write, stderr, stdout},
+    panic,
+    sync::Once,
+};
+
+thread_local! {
+    static BUFFER: RefCell<Option<Captured>> = const { RefCell::new(None) };
+}
+
+/// The stream that output is written to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stream {
+    Stdout,
+    Stderr,
+}
+
+/// Output captured on a thread, in the order it was written.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Captured {
+    chunks: Vec<(Stream, String)>,
+}
+
+impl Captured {
+    fn push(&mut self, stream: Stream, args: Arguments) {
+        match self.chunks.last_mut() {
+            Some((s, chunk)) if *s == stream => {
+                let _ = chunk.write_fmt(args);
+            }
+            _ => self.chunks.push((stream, args.to_string())),
+        }
+    }
+
+    /// Writes the captured output to stdout and stderr.
+    pub fn print(&self) {
+        for (stream, chunk) in &self.chunks {
+            write_to(*stream, format_args!("{chunk}"));
+        }
+    }
+}
+
+/// Runs `f` while buffering all output written on this thread, and returns it.
+pub fn capture<R>(f: impl FnOnce() -> R) -> (R, Captured) {
+    let previous = BUFFER.with(|b| b.borrow_mut().replace(Captured::default()));
+    let result = f();
+    let output = BUFFER.with(|b| std::mem::replace(&mut *b.borrow_mut(), previous));
+    (result, output.unwrap_or_default())
+}
+
+/// Writes to the capture buffer of this thread, or to the stream if nothing is captured.
+pub fn write(stream: Stream, args: Arguments) {
+    let is_captured = BUFFER.with(|b| match b.borrow_mut().as_mut() {
+        Some(buffer) => {
+            buffer.push(stream, args);
+            true
+        }
+        None => false,
+    });
+
+    if !is_captured {
+        write_to(stream, args);
+    }
+}
+
+fn write_to(stream: Stream, args: Arguments) {
+    match stream {
+        Stream::Stdout => {
+            let mut stdout = stdout();
+            let _ = stdout.write_fmt(args);
+            let _ = stdout.flush();
+        }
+        Stream::Stderr => {
+            let _ = stderr().write_fmt(args);
+        }
+    }
+}
+
+/// Writes panic messages of threads that capture their output to the capture buffer, so they
+/// are printed with the output of their day. Other panics are reported by the previous hook.
+pub fn capture_panics() {
+    static INSTALL: Once = Once::new();
+
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let is_captured = BUFFER.with(|b| b.try_borrow().is_ok_and(|b| b.is_some()));
+            if is_captured {
+                write(Stream::Stderr, format_args!("{info}\n"));
+            } else {
+                previous(info);
+            }
+        }));
+    });
+}
+
+/// Like `print!`, but respects [`capture`].
+macro_rules! out {
+    ($($arg:tt)*) => {
+        $crate::template::output::write(
+            $crate::template::output::Stream::Stdout,
+            format_args!($($arg)*),
+        )
+    };
+}
+
+/// Like `println!`, but respects [`capture`].
+macro_rules! outln {
+    () => {
+        $crate::template::output::write(
+            $crate::template::output::Stream::Stdout,
+            format_args!("\n"),
+        )
+    };
+    ($($arg:tt)*) => {
+        $crate::template::output::write(
+            $crate::template::output::Stream::Stdout,
+            format_args!("{}\n", format_args!($($arg)*)),
+        )
+    };
+}
+
+/// Like `eprintln!`, but respects [`capture`].
+macro_rules! eoutln {
+    ($($arg:tt)*) => {
+        $crate::template::output::write(
+            $crate::template::output::Stream::Stderr,
+            format_args!("{}\n", format_args!($($arg)*)),
+        )
+    };
+}
+
+pub(crate) use {eoutln, out, outln};
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Stream, capture};
+
+    #[test]
+    fn captures_output() {
+        let (result, output) = capture(|| {
+            out!("Part 1: ");
+            outln!("{}", 42);
+            outln!();
+            7
+        });
+        assert_eq!(result, 7);
+        assert_eq!(output.chunks, [(Stream::Stdout, "Part 1: 42\n\n".into())]);
+    }
+
+    #[test]
+    fn captures_nested_output() {
+        let (_, outer) = capture(|| {
+            outln!("outer");
+            let (_, inner) = capture(|| outln!("inner"));
+            assert_eq!(inner.chunks, [(Stream::Stdout, "inner\n".into())]);
+        });
+        assert_eq!(outer.chunks, [(Stream::Stdout, "outer\n".into())]);
+    }
+
+    #[test]
+    fn captures_stderr_in_order() {
+        let (_, output) = capture(|| {
+            outln!("Part 1: 42");
+            eoutln!("warning");
+            outln!("Part 2: 7");
+        });
+        assert_eq!(
+            output.chunks,
+            [
+                (Stream::Stdout, "Part 1: 42\n".into()),
+                (Stream::Stderr, "warning\n".into()),
+                (Stream::Stdout, "Part 2: 7\n".into()),
+            ]
+        );
+    }
+}
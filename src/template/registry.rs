@@ -0,0 +1,26 @@
+/// Solutions that are linked into the `advent_of_code` binary, so `all` and `time` can run them
+/// without spawning `cargo` for every day.
+///
+/// The registry itself is generated by `build.rs`, which includes every scaffolded
+/// `src/bin/YYYY_DD.rs` of the configured year as a module of the main binary.
+use crate::template::{Day, report::PartReport, runner::RunOptions};
+
+/// Runs all parts of a day against its puzzle input. Generated by [`crate::solution`].
+pub type DayRunner = fn(&RunOptions) -> Vec<PartReport>;
+
+/// Maps days to their linked solutions.
+#[derive(Clone, Default)]
+pub struct Registry {
+    days: Vec<(Day, DayRunner)>,
+}
+
+impl Registry {
+    pub fn new(days: Vec<(Day, DayRunner)>) -> Self {
+        Self { days }
+    }
+
+    /// Returns the linked solution of a day, if any.
+    pub fn get(&self, day: Day) -> Option<DayRunner> {
+        self.days.iter().find(|(d, _)| *d == day).map(|(_, f)| *f)
+    }
+}
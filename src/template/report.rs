@@ -0,0 +1,232 @@
+/// Structured records that solution binaries hand back to `run_multi`.
+///
+/// When the `AOC_REPORT_FILE` environment variable is set, every call to `run_part` appends one
+/// JSON line describing the part to that file. The parent process reads these records instead of
+/// parsing the human-readable output, so answers may contain any character.
+use std::{
+    collections::HashMap,
+    env, fs,
+    io::{self, Write},
+    path::Path,
+    str::FromStr,
+    time::Duration,
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::{
+    Day,
+    limits::{RunFailure, failure_from_json, failure_to_json},
+    stats::{BenchStats, stats_from_json, stats_to_json},
+};
+
+pub const REPORT_FILE_ENV: &str = "AOC_REPORT_FILE";
+
+/// The part number of reports that time the parsing of the input, see `solution!(N, parse = ..)`.
+pub const PARSE_PART: u8 = 0;
+
+/// Formats a part number for display, e.g. `Part 1` or `Parse`.
+pub fn part_label(part: u8) -> String {
+    if part == PARSE_PART {
+        "Parse".into()
+    } else {
+        format!("Part {part}")
+    }
+}
+
+/// Outcome of running a single part of a solution, or of parsing its input ([`PARSE_PART`]).
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartReport {
+    pub day: Day,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub duration: Duration,
+    pub samples: u128,
+    pub stats: Option<BenchStats>,
+    /// Set if the part was stopped by a limit before it produced an answer.
+    pub failure: Option<RunFailure>,
+    /// Set if the part returned an error instead of an answer.
+    pub error: Option<String>,
+}
+
+impl PartReport {
+    /// Append this record to the report file if the parent process requested one.
+    pub fn write_if_requested(&self) -> Result<(), io::Error> {
+        match env::var(REPORT_FILE_ENV) {
+            Ok(path) if !path.is_empty() => self.append_to(Path::new(&path)),
+            _ => Ok(()),
+        }
+    }
+
+    fn append_to(&self, path: &Path) -> Result<(), io::Error> {
+        let line = JsonValue::from(self)
+            .stringify()
+            .map_err(|e| io::Error::other(e.to_string()))?;
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?;
+        writeln!(file, "{line}")
+    }
+}
+
+/// Read all records from a report file. A missing file yields no records.
+pub fn read_file(path: &Path) -> Result<Vec<PartReport>, String> {
+    match fs::read_to_string(path) {
+        Ok(contents) => parse_lines(&contents),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Parse JSON lines into records, skipping blank lines.
+pub fn parse_lines(contents: &str) -> Result<Vec<PartReport>, String> {
+    contents
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| {
+            let json = JsonValue::from_str(l).or(Err("report line is not valid JSON."))?;
+            PartReport::try_from(&json)
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartReport> for JsonValue {
+    fn from(value: &PartReport) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert(
+            "answer".into(),
+            match &value.answer {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "nanos".into(),
+            JsonValue::Number(value.duration.as_nanos() as f64),
+        );
+        #[allow(clippy::cast_precision_loss)]
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert("stats".into(), stats_to_json(value.stats.as_ref()));
+        map.insert("failure".into(), failure_to_json(value.failure));
+        map.insert(
+            "error".into(),
+            match &value.error {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartReport {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected report to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected report.day to be a Day struct.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected report.part to be a number.")? as u8;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected report.answer to be null or string.")?;
+
+        let nanos = json
+            .get("nanos")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected report.nanos to be a number.")?;
+
+        let samples = json
+            .get("samples")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected report.samples to be a number.")?;
+
+        let stats = stats_from_json(json, "stats")?;
+        let failure = failure_from_json(json, "failure")?;
+
+        // reports of older binaries have no error.
+        let error = match json.get("error") {
+            None | Some(JsonValue::Null) => None,
+            Some(JsonValue::String(x)) => Some(x.clone()),
+            Some(_) => return Err("Expected report.error to be null or string.".into()),
+        };
+
+        Ok(PartReport {
+            day,
+            part,
+            answer: answer.cloned(),
+            duration: Duration::from_nanos(nanos as u64),
+            samples: samples as u128,
+            stats,
+            failure,
+            error,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use tinyjson::JsonValue;
+
+    use super::{PartReport, parse_lines};
+    use crate::day;
+
+    #[test]
+    fn roundtrips_reports() {
+        let report = PartReport {
+            day: day!(3),
+            part: 2,
+            answer: Some("multi\nline (42 @ 1 samples)".into()),
+            duration: Duration::from_nanos(74_130),
+            samples: 100,
+            stats: None,
+            failure: None,
+            error: Some("invalid digit found in string".into()),
+        };
+        let line = JsonValue::from(&report).stringify().unwrap();
+        assert!(!line.contains('\n'));
+        assert_eq!(parse_lines(&line).unwrap(), vec![report]);
+    }
+
+    #[test]
+    fn parses_missing_answers() {
+        let reports = parse_lines(
+            "{\"day\":\"01\",\"part\":1,\"answer\":null,\"nanos\":10,\"samples\":1}\n\n",
+        )
+        .unwrap();
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].answer, None);
+        assert_eq!(reports[0].error, None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_reports() {
+        parse_lines("{\"day\":\"01\",\"part\":1}").unwrap();
+    }
+}
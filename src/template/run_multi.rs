@@ -1,38 +1,144 @@
-use std::{collections::HashSet, io};
+use std::{
+    collections::HashSet,
+    fmt::Display,
+    io,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    process,
+    time::Duration,
+};
+
+use rayon::{ThreadPoolBuilder, prelude::*};
 
-use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day, Year, get_data_dir};
 
 use super::{
     all_days,
+    answers::{AnswerCheck, Answers},
+    limits::{Limits, RunFailure},
+    output::{self, Captured, eoutln, outln},
+    registry::{DayRunner, Registry},
+    report::{PartReport, part_label},
+    runner::{RunOptions, panic_message},
     timings::{Timing, Timings},
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
+/// Run the solutions for a set of days.
+///
+/// Days linked into the `registry` are run in-process, all other days (or all days if no registry
+/// is passed) are run as child processes.
+///
+/// With `is_checked`, every part is compared against the accepted answers and the process exits
+/// with a non-zero status if any of them regressed.
+///
+/// With more than one of `jobs`, days of a non-timed run are executed concurrently.
+///
+/// Days that exceed the `limits` are stopped and their missing parts are reported as failed.
+/// Since in-process runs cannot be stopped, every day runs as a child process if limits are set.
+/// Linked days are optimized like this binary, so they also run as child processes if
+/// `is_release` asks for a different build.
+pub fn run_multi(
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    is_timed: bool,
+    is_checked: bool,
+    registry: Option<&Registry>,
+    jobs: usize,
+    limits: Limits,
+) -> Option<Timings> {
     let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
-
-    let mut need_space = false;
+    let answers = Answers::read_from_file(Year::current());
+    let mut regressions: Vec<(Day, u8)> = vec![];
+    let mut failures: Vec<(Day, u8, RunFailure)> = vec![];
+    let mut errors: Vec<(Day, u8, String)> = vec![];
+    // NOTE: linked days run with the profile of this binary, so they are only used if it matches.
+    let registry =
+        registry.filter(|_| limits.is_empty() && is_release == cfg!(not(debug_assertions)));
 
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
-            }
-            need_space = true;
+    let days: Vec<Day> = all_days().filter(|day| days_to_run.contains(day)).collect();
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+    let run = |(index, day): (usize, Day)| {
+        if index > 0 {
+            outln!();
+        }
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+        outln!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        outln!("------");
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
-            }
-        });
+        let reports = run_day(day, is_release, is_timed, is_checked, registry, limits);
+
+        if reports.is_empty() {
+            outln!("Not solved.");
+        }
+
+        reports
+    };
+
+    // NOTE: concurrent runs would skew timings, so benchmarks always run sequentially.
+    let day_reports = if jobs > 1 && !is_timed {
+        run_parallel(&days, jobs, run)
+    } else {
+        days.iter().copied().enumerate().map(run).collect()
+    };
+
+    for (day, reports) in days.into_iter().zip(day_reports) {
+        if reports.is_empty() {
+            continue;
+        }
+
+        failures.extend(
+            reports
+                .iter()
+                .filter_map(|r| r.failure.map(|f| (r.day, r.part, f))),
+        );
+
+        errors.extend(
+            reports
+                .iter()
+                .filter_map(|r| r.error.clone().map(|e| (r.day, r.part, e))),
+        );
+
+        if is_checked {
+            regressions.extend(reports.iter().filter_map(|r| {
+                match answers.check(r.day, r.part, r.answer.as_deref()) {
+                    AnswerCheck::Wrong { .. } => Some((r.day, r.part)),
+                    _ => None,
+                }
+            }));
+        }
+
+        let val = child_commands::timing_from_reports(&reports, day);
+        timings.push(val);
+    }
+
+    if !failures.is_empty() {
+        let parts = failures
+            .iter()
+            .map(|(day, part, failure)| format!("Day {day} {} ({failure})", part_label(*part)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        eprintln!("\n{ANSI_BOLD}⏱ Stopped by limits:{ANSI_RESET} {parts}");
+    }
+
+    if !errors.is_empty() {
+        let parts = errors
+            .iter()
+            .map(|(day, part, error)| format!("Day {day} {} ({error})", part_label(*part)))
+            .collect::<Vec<_>>()
+            .join(", ");
+        eprintln!("\n{ANSI_BOLD}✖ Failed with an error:{ANSI_RESET} {parts}");
+    }
+
+    if !regressions.is_empty() {
+        let parts = regressions
+            .iter()
+            .map(|(day, part)| format!("Day {day} Part {part}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        eprintln!("\n{ANSI_BOLD}✘ Answers changed:{ANSI_RESET} {parts}");
+        process::exit(1);
+    }
 
     if is_timed {
         let timings = Timings { data: timings };
@@ -46,11 +152,110 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
     }
 }
 
+/// Run days on a thread pool. The output of each day is buffered and printed in day order.
+/// A panic fails the day it happened in, the output of all other days is kept.
+fn run_parallel(
+    days: &[Day],
+    jobs: usize,
+    run: impl Fn((usize, Day)) -> Vec<PartReport> + Sync,
+) -> Vec<Vec<PartReport>> {
+    let pool = ThreadPoolBuilder::new()
+        .num_threads(jobs)
+        .build()
+        .expect("could not create thread pool");
+
+    output::capture_panics();
+
+    let results: Vec<(Vec<PartReport>, Captured)> = pool.install(|| {
+        days.par_iter()
+            .copied()
+            .enumerate()
+            .map(|x| {
+                output::capture(|| {
+                    panic::catch_unwind(AssertUnwindSafe(|| run(x))).unwrap_or_else(|payload| {
+                        eoutln!("✖ {}", panic_message(&*payload));
+                        vec![]
+                    })
+                })
+            })
+            .collect()
+    });
+
+    results
+        .into_iter()
+        .map(|(reports, output)| {
+            output.print();
+            reports
+        })
+        .collect()
+}
+
+fn run_day(
+    day: Day,
+    is_release: bool,
+    is_timed: bool,
+    is_checked: bool,
+    registry: Option<&Registry>,
+    limits: Limits,
+) -> Vec<PartReport> {
+    match registry.and_then(|r| r.get(day)) {
+        // NOTE: days without input run as a child process, which reports the missing file.
+        Some(run) if Path::new(&get_path_for_input(day)).exists() => {
+            run_linked(day, run, is_timed, is_checked)
+        }
+        _ => child_commands::run_solution(day, is_timed, is_release, is_checked, limits).unwrap(),
+    }
+}
+
+/// Run a linked solution in-process. Panics of single parts are reported by the runner, any
+/// other panic fails all parts of the day instead of aborting the whole run.
+fn run_linked(day: Day, run: DayRunner, is_timed: bool, is_checked: bool) -> Vec<PartReport> {
+    let options = RunOptions {
+        time: is_timed,
+        check: is_checked,
+        submit: None,
+        timeout: None,
+    };
+
+    panic::catch_unwind(|| run(&options)).unwrap_or_else(|payload| {
+        let error = panic_message(&*payload);
+        outln!("✖ {error}");
+        failed_parts(day, &error)
+    })
+}
+
+/// Reports both parts of a day as failed with the same `error`, e.g. if the day did not run at all.
+fn failed_parts(day: Day, error: &str) -> Vec<PartReport> {
+    (1..=2)
+        .map(|part| PartReport {
+            day,
+            part,
+            answer: None,
+            duration: Duration::ZERO,
+            samples: 0,
+            stats: None,
+            failure: None,
+            error: Some(error.into()),
+        })
+        .collect()
+}
+
 #[allow(dead_code)]
 #[derive(Debug)]
 pub enum Error {
     BrokenPipe,
     IO(io::Error),
+    Report(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::BrokenPipe => f.write_str("output of the solution is not available"),
+            Error::IO(e) => write!(f, "{e}"),
+            Error::Report(e) => write!(f, "invalid report: {e}"),
+        }
+    }
 }
 
 impl From<std::io::Error> for Error {
@@ -61,132 +266,269 @@ impl From<std::io::Error> for Error {
 
 #[must_use]
 pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+    format!("./src/bin/{}.rs", Year::current().bin_name(day))
+}
+
+#[must_use]
+pub fn get_path_for_input(day: Day) -> String {
+    get_data_dir(Year::current(), "inputs")
+        .join(format!("{day}.txt"))
+        .display()
+        .to_string()
 }
 
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// This module encapsulates interaction with these binaries, both invoking them as well as collecting their reports.
 pub mod child_commands {
-    use super::{Error, get_path_for_bin};
-    use crate::template::Day;
+    use super::{Error, failed_parts, get_path_for_bin};
+    use crate::template::{
+        Day, Year,
+        limits::{Limits, POLL_INTERVAL, TIMEOUT_ENV},
+        output::{self, Stream, eoutln, outln},
+        report::{self, PARSE_PART, PartReport, REPORT_FILE_ENV},
+    };
     use std::{
-        io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
-        thread,
+        collections::HashMap,
+        env, fs,
+        io::{BufRead, BufReader, Read},
+        path::{Path, PathBuf},
+        process::{self, Command, Stdio},
+        str::FromStr,
+        sync::mpsc::{self, RecvTimeoutError, Sender},
+        thread::{self, JoinHandle},
+        time::{Duration, Instant},
     };
+    use tinyjson::JsonValue;
+
+    /// Build the solution bin for a given day and return the path of its executable.
+    ///
+    /// Running the executable directly (instead of via `cargo run`) allows stopping it.
+    /// Returns `None` if the build failed, its diagnostics are written to stderr.
+    pub fn build_solution(day: Day, cargo_args: &[&str]) -> Result<Option<PathBuf>, Error> {
+        let bin_name = Year::current().bin_name(day);
+
+        let output = Command::new("cargo")
+            .args(["build", "--quiet", "--bin", &bin_name])
+            .args(cargo_args)
+            .arg("--message-format=json-render-diagnostics")
+            .output()?;
+
+        // rendered diagnostics go to stderr, which respects the capture of parallel runs.
+        output::write(
+            Stream::Stderr,
+            format_args!("{}", String::from_utf8_lossy(&output.stderr)),
+        );
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+        if !output.status.success() {
+            return Ok(None);
+        }
+
+        // cargo reports one JSON message per line, the executable is part of the artifact message.
+        let executable = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|l| JsonValue::from_str(l).ok())
+            .find_map(|json| {
+                let message = json.get::<HashMap<String, JsonValue>>()?;
+                let path = message.get("executable")?.get::<String>()?;
+                Some(PathBuf::from(path))
+            });
+
+        Ok(executable)
+    }
+
+    /// Run the solution bin for a given day, stopping it if it exceeds the `limits`.
+    /// Parts that did not report before the solution was stopped are reported as failed.
+    pub fn run_solution(
+        day: Day,
+        is_timed: bool,
+        is_release: bool,
+        is_checked: bool,
+        limits: Limits,
+    ) -> Result<Vec<PartReport>, Error> {
         // skip command invocation for days that have not been scaffolded yet.
         if !Path::new(&get_path_for_bin(day)).exists() {
             return Ok(vec![]);
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        let cargo_args: &[&str] = if is_release { &["--release"] } else { &[] };
+        let Some(executable) = build_solution(day, cargo_args)? else {
+            let error = "build failed";
+            outln!("✖ {error}");
+            return Ok(failed_parts(day, error));
+        };
 
-        if is_release {
-            args.push("--release");
-        }
+        let mut args = vec![];
 
         if is_timed {
             // mirror `--time` flag to child invocations.
-            args.push("--");
             args.push("--time");
         }
 
+        if is_checked {
+            // mirror `--check` flag to child invocations.
+            args.push("--check");
+        }
+
+        // the child appends one JSON record per part to this file.
+        let report_path = get_report_path(day);
+        let _ = fs::remove_file(&report_path);
+
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // forward output to stdout/stderr.
+
+        let mut cmd = Command::new(executable);
+        cmd.args(&args).env(REPORT_FILE_ENV, &report_path);
+
+        // benchmarks would count towards the timeout, so the child only applies it to the first
+        // run of each part.
+        let limits = match limits.timeout {
+            Some(timeout) if is_timed => {
+                cmd.env(TIMEOUT_ENV, timeout.as_secs_f64().to_string());
+                Limits {
+                    timeout: None,
+                    ..limits
+                }
+            }
+            _ => limits,
+        };
 
-        let mut cmd = Command::new("cargo")
-            .args(&args)
-            .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
-            .spawn()?;
+        let mut cmd = cmd.stdout(Stdio::piped()).stderr(Stdio::piped()).spawn()?;
 
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
+        let stdout = cmd.stdout.take().ok_or(super::Error::BrokenPipe)?;
+        let stderr = cmd.stderr.take().ok_or(super::Error::BrokenPipe)?;
 
-        let mut output = vec![];
+        // read both streams on separate threads so the limits can be checked while waiting for
+        // output. Lines are printed on this thread, so they end up in its capture buffer.
+        let (tx, rx) = mpsc::channel();
+        let stdout_thread = forward_lines(stdout, tx.clone(), ChildLine::Stdout);
+        let stderr_thread = forward_lines(stderr, tx, ChildLine::Stderr);
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
-        });
+        let started = Instant::now();
+        let mut failure = None;
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+        loop {
+            match rx.recv_timeout(POLL_INTERVAL) {
+                Ok(ChildLine::Stdout(line)) => outln!("{line}"),
+                Ok(ChildLine::Stderr(line)) => eoutln!("{line}"),
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+
+            if failure.is_none() {
+                failure = limits.enforce(&mut cmd, started);
+            }
         }
 
-        thread.join().unwrap();
+        stdout_thread.join().unwrap();
+        stderr_thread.join().unwrap();
         cmd.wait()?;
 
-        Ok(output)
+        let mut reports = report::read_file(&report_path).map_err(super::Error::Report)?;
+        let _ = fs::remove_file(&report_path);
+
+        // a child that stopped itself reports the failure of the part it stopped at.
+        let failure = failure.or_else(|| reports.iter().find_map(|r| r.failure));
+
+        if let Some(failure) = failure {
+            for part in 1..=2 {
+                if reports.iter().any(|r| r.part == part) {
+                    continue;
+                }
+
+                outln!("Part {part}: ⏱ {failure} after {:.1?}", started.elapsed());
+                reports.push(PartReport {
+                    day,
+                    part,
+                    answer: None,
+                    duration: started.elapsed(),
+                    samples: 0,
+                    stats: None,
+                    failure: Some(failure),
+                    error: None,
+                });
+            }
+        }
+
+        Ok(reports)
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
+    /// A line of output of a solution bin.
+    enum ChildLine {
+        Stdout(String),
+        Stderr(String),
+    }
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
+    /// Sends every line of a child's output stream to `tx` until the stream is closed.
+    fn forward_lines(
+        stream: impl Read + Send + 'static,
+        tx: Sender<ChildLine>,
+        line: fn(String) -> ChildLine,
+    ) -> JoinHandle<()> {
+        thread::spawn(move || {
+            for l in BufReader::new(stream).lines().map_while(Result::ok) {
+                if tx.send(line(l)).is_err() {
+                    break;
                 }
+            }
+        })
+    }
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+    /// Returns the file that the solution bin of a day appends its reports to.
+    pub fn get_report_path(day: Day) -> PathBuf {
+        env::temp_dir().join(format!("aoc-report-{}-{day}.jsonl", process::id()))
+    }
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
+    /// Build the timing of a day from the reports of its parts.
+    /// Parts without an answer are not counted, but stopped parts keep their failure.
+    /// The parse time, if reported separately, counts towards the total.
+    pub fn timing_from_reports(reports: &[PartReport], day: Day) -> super::Timing {
+        let mut timings = super::Timing::new(day);
+
+        for r in reports.iter().filter(|r| r.day == day) {
+            match r.part {
+                PARSE_PART => {
+                    timings.parse = Some(format_timing(&r.duration));
+                    timings.parse_stats = r.stats;
+
+                    #[allow(clippy::cast_precision_loss)]
+                    {
+                        timings.total_nanos += r.duration.as_nanos() as f64;
+                    }
                 }
+                1 => timings.part_1_failure = r.failure,
+                2 => timings.part_2_failure = r.failure,
+                _ => {}
+            }
+        }
 
-                timings.total_nanos += nanos;
+        reports
+            .iter()
+            .filter(|r| r.day == day && r.answer.is_some())
+            .for_each(|r| {
+                let timing_str = format_timing(&r.duration);
+                match r.part {
+                    1 => {
+                        timings.part_1 = Some(timing_str);
+                        timings.part_1_stats = r.stats;
+                    }
+                    2 => {
+                        timings.part_2 = Some(timing_str);
+                        timings.part_2_stats = r.stats;
+                    }
+                    _ => return,
+                }
+
+                #[allow(clippy::cast_precision_loss)]
+                {
+                    timings.total_nanos += r.duration.as_nanos() as f64;
+                }
             });
 
         timings
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
-    }
-
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .next_back()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+    fn format_timing(duration: &Duration) -> String {
+        format!("{duration:.1?}")
     }
 
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
@@ -205,50 +547,72 @@ pub mod child_commands {
 
     #[cfg(feature = "test_lib")]
     mod tests {
-        use super::parse_exec_time;
-
-        use crate::day;
+        use std::time::Duration;
+
+        use super::timing_from_reports;
+
+        use crate::{day, template::report::PartReport};
+
+        fn report(part: u8, answer: Option<&str>, nanos: u64) -> PartReport {
+            PartReport {
+                day: day!(1),
+                part,
+                answer: answer.map(Into::into),
+                duration: Duration::from_nanos(nanos),
+                samples: 100,
+                stats: None,
+                failure: None,
+                error: None,
+            }
+        }
 
         #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
+        fn collects_execution_times() {
+            let res = timing_from_reports(
                 &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
+                    report(1, Some("0"), 74_130),
+                    report(2, Some("10"), 74_130_000),
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_approx_eq!(res.total_nanos, 74_204_130_f64);
+            assert_eq!(res.part_1.unwrap(), "74.1µs");
+            assert_eq!(res.part_2.unwrap(), "74.1ms");
         }
 
         #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
+        fn collects_with_patterns_in_answers() {
+            let res = timing_from_reports(
                 &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
+                    report(1, Some("@ @ @ ( ) ms (2s @ 5 samples)"), 2_000_000_000),
+                    report(2, Some("10s\n(100ms @ 1 samples)"), 100_000_000),
                 ],
                 day!(1),
             );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_approx_eq!(res.total_nanos, 2_100_000_000_f64);
+            assert_eq!(res.part_1.unwrap(), "2.0s");
+            assert_eq!(res.part_2.unwrap(), "100.0ms");
         }
 
         #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
+        fn collects_parse_times() {
+            let res = timing_from_reports(
                 &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
+                    report(0, None, 1_000_000),
+                    report(1, Some("0"), 2_000_000),
+                    report(2, Some("10"), 3_000_000),
                 ],
                 day!(1),
             );
+            assert_approx_eq!(res.total_nanos, 6_000_000_f64);
+            assert_eq!(res.parse.unwrap(), "1.0ms");
+            assert_eq!(res.part_1.unwrap(), "2.0ms");
+        }
+
+        #[test]
+        fn collects_missing_parts() {
+            let res =
+                timing_from_reports(&[report(1, None, 1_000), report(2, None, 1_000)], day!(1));
             assert_approx_eq!(res.total_nanos, 0_f64);
             assert_eq!(res.part_1.is_none(), true);
             assert_eq!(res.part_2.is_none(), true);
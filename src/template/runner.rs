@@ -1,60 +1,316 @@
 /// Encapsulates code that interacts with solution functions.
+use std::any::Any;
 use std::fmt::Display;
 use std::hint::black_box;
-use std::io::{Write, stdout};
-use std::process::Output;
+use std::panic::{self, AssertUnwindSafe};
 use std::time::{Duration, Instant};
 use std::{cmp, env, process};
 
 use crate::template::ANSI_BOLD;
-use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, aoc_cli};
+use crate::template::answers::{AnswerCheck, Answers, Hint};
+use crate::template::aoc_cli::SubmissionOutcome;
+use crate::template::client::{Client, ClientError};
+use crate::template::limits::{self, RunFailure};
+use crate::template::output::{out, outln};
+use crate::template::report::{PARSE_PART, PartReport, part_label};
+use crate::template::stats::BenchStats;
+use crate::template::{ANSI_ITALIC, ANSI_RED, ANSI_RESET, Day, Year, aoc_cli};
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
-    let part_str = format!("Part {part}");
+/// Options that control how solution parts are run.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RunOptions {
+    /// Bench each part instead of running it once.
+    pub time: bool,
+    /// Compare each part against the accepted answers.
+    pub check: bool,
+    /// The part to submit, if any.
+    pub submit: Option<u8>,
+    /// Stops the solution if the first run of a part exceeds it, see [`limits::TIMEOUT_ENV`].
+    /// Only set in solution binaries, never for solutions that run in-process.
+    pub timeout: Option<Duration>,
+}
+
+impl RunOptions {
+    /// Read the options passed to a solution binary, e.g. `--time` or `--submit 1`.
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+
+        let submit = args.iter().position(|x| x == "--submit").map(|index| {
+            let Some(Ok(part)) = args.get(index + 1).map(|x| x.parse::<u8>()) else {
+                eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
+                process::exit(1);
+            };
+            part
+        });
+
+        RunOptions {
+            time: args.iter().any(|x| x == "--time"),
+            check: args.iter().any(|x| x == "--check"),
+            submit,
+            timeout: limits::timeout_from_env(),
+        }
+    }
+}
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+/// Parses the puzzle input into a type that is shared by both parts.
+///
+/// Implementors can be passed to `solution!` as `parse = Type::parse`. If parsing fails, the error
+/// is printed and the parts are skipped.
+pub trait Parse: Sized {
+    type Error: Display;
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    fn parse(input: &str) -> Result<Self, Self::Error>;
+}
+
+/// The outcome of a solution part, as reported by [`PartResult`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PartOutcome {
+    Solved(String),
+    /// The part returned `None`, e.g. because it is not implemented yet.
+    Unsolved,
+    /// The part returned an error, e.g. because the input could not be parsed.
+    Failed(String),
+}
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+/// Return types of solution parts: `Option<T>`, `Result<T, E>` or a plain answer like `u64`.
+pub trait PartResult {
+    fn outcome(&self) -> PartOutcome;
+}
+
+impl<T: Display> PartResult for Option<T> {
+    fn outcome(&self) -> PartOutcome {
+        match self {
+            Some(answer) => PartOutcome::Solved(answer.to_string()),
+            None => PartOutcome::Unsolved,
+        }
     }
 }
 
+impl<T: Display, E: Display> PartResult for Result<T, E> {
+    fn outcome(&self) -> PartOutcome {
+        match self {
+            Ok(answer) => PartOutcome::Solved(answer.to_string()),
+            Err(e) => PartOutcome::Failed(e.to_string()),
+        }
+    }
+}
+
+/// Implements [`PartResult`] for answers that are returned as is.
+macro_rules! impl_plain_part_result {
+    ($($t:ty),*) => {
+        $(
+            impl PartResult for $t {
+                fn outcome(&self) -> PartOutcome {
+                    PartOutcome::Solved(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_plain_part_result!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, char, String, &str
+);
+
+/// Parse the input once for all parts. The parse time is reported like a part, see [`PARSE_PART`].
+/// Returns an error instead of the parsed input if parsing failed or panicked, the parts are
+/// skipped then.
+pub fn run_parse<T, E: Display>(
+    func: impl Fn(&str) -> Result<T, E>,
+    input: &str,
+    day: Day,
+    options: &RunOptions,
+) -> (Result<T, String>, PartReport) {
+    let label = part_label(PARSE_PART);
+
+    let (parsed, duration, samples, stats) =
+        run_timed(func, input, day, PARSE_PART, options, Result::is_ok, |_| {
+            out!("{label}:");
+        });
+    let parsed = parsed.and_then(|parsed| parsed.map_err(|e| e.to_string()));
+
+    out!("\r");
+    match &parsed {
+        Ok(_) => outln!("{label}:{}", format_duration(&duration, samples)),
+        Err(e) => outln!("{label}: ✖ {ANSI_RED}{e}{ANSI_RESET}"),
+    }
+
+    if let Some(stats) = &stats {
+        outln!("        {ANSI_ITALIC}{stats}{ANSI_RESET}");
+    }
+
+    let report = PartReport {
+        day,
+        part: PARSE_PART,
+        answer: None,
+        duration,
+        samples,
+        stats,
+        failure: None,
+        error: parsed.as_ref().err().cloned(),
+    };
+
+    if let Err(e) = report.write_if_requested() {
+        eprintln!("Failed to write report for {label}: {e}");
+    }
+
+    (parsed, report)
+}
+
+pub fn run_part<I: Copy, R: PartResult>(
+    func: impl Fn(I) -> R,
+    input: I,
+    year: Year,
+    day: Day,
+    part: u8,
+    options: &RunOptions,
+) -> PartReport {
+    let part_str = part_label(part);
+
+    let (result, duration, samples, stats) = run_timed(
+        func,
+        input,
+        day,
+        part,
+        options,
+        |_| true,
+        |result| {
+            print_result(&part_outcome(result), &part_str, "", "");
+        },
+    );
+
+    let outcome = part_outcome(&result);
+    let (answer, error) = match &outcome {
+        PartOutcome::Solved(answer) => (Some(answer.clone()), None),
+        PartOutcome::Unsolved => (None, None),
+        PartOutcome::Failed(e) => (None, Some(e.clone())),
+    };
+
+    let check_str = if options.check {
+        check_result(answer.as_deref(), year, day, part)
+    } else {
+        String::new()
+    };
+
+    print_result(
+        &outcome,
+        &part_str,
+        &format_duration(&duration, samples),
+        &check_str,
+    );
+
+    if let Some(stats) = &stats {
+        outln!("        {ANSI_ITALIC}{stats}{ANSI_RESET}");
+    }
+
+    let report = PartReport {
+        day,
+        part,
+        answer,
+        duration,
+        samples,
+        stats,
+        failure: None,
+        error,
+    };
+
+    if let Err(e) = report.write_if_requested() {
+        eprintln!("Failed to write report for {part_str}: {e}");
+    }
+
+    if let Some(answer) = &report.answer
+        && options.submit == Some(part)
+    {
+        submit_result(answer, year, day, part);
+    }
+
+    report
+}
+
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
 ///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///
+/// A panic of the function is returned as an error, so it does not stop other days that run in
+/// the same process. Parts that panicked, or whose result is not `is_benched`, are not benched.
+///
+/// The timeout of the `options` applies to the first run only, see [`limits::TIMEOUT_ENV`].
 fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
-    hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+    day: Day,
+    part: u8,
+    options: &RunOptions,
+    is_benched: impl Fn(&T) -> bool,
+    hook: impl Fn(&Result<T, String>),
+) -> (Result<T, String>, Duration, u128, Option<BenchStats>) {
     let timer = Instant::now();
-    let result = {
-        #[cfg(feature = "dhat-heap")]
-        let _profiler = dhat::Profiler::new_heap();
+    let result = limits::with_timeout(
+        options.timeout,
+        || {
+            #[cfg(feature = "dhat-heap")]
+            let _profiler = dhat::Profiler::new_heap();
 
-        func(input)
-    };
+            panic::catch_unwind(AssertUnwindSafe(|| func(input))).map_err(|e| panic_message(&*e))
+        },
+        move || report_timeout(day, part, timer.elapsed()),
+    );
     let base_time = timer.elapsed();
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    if options.time && result.as_ref().is_ok_and(is_benched) {
+        let stats = bench(func, input, &base_time);
+        (result, stats.mean, stats.samples, Some(stats))
     } else {
-        (base_time, 1)
+        (result, base_time, 1, None)
+    }
+}
+
+/// Describes the payload of a caught panic, e.g. `panicked: index out of bounds`.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    let message = payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown cause");
+    format!("panicked: {message}")
+}
+
+/// Report a part that exceeded the timeout of the parent process and stop the solution.
+fn report_timeout(day: Day, part: u8, elapsed: Duration) {
+    let label = part_label(part);
+    let failure = RunFailure::TimedOut;
+
+    println!("\r{label}: ⏱ {failure} after {elapsed:.1?}");
+
+    let report = PartReport {
+        day,
+        part,
+        answer: None,
+        duration: elapsed,
+        samples: 0,
+        stats: None,
+        failure: Some(failure),
+        error: None,
     };
 
-    (result, run.0, run.1)
+    if let Err(e) = report.write_if_requested() {
+        eprintln!("Failed to write report for {label}: {e}");
+    }
+
+    process::exit(1);
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
+fn part_outcome<R: PartResult>(result: &Result<R, String>) -> PartOutcome {
+    match result {
+        Ok(result) => result.outcome(),
+        Err(e) => PartOutcome::Failed(e.clone()),
+    }
+}
 
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> BenchStats {
+    out!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
 
     let bench_iterations =
         (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
@@ -67,19 +323,17 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    // NOTE: there are at least 10 samples, so stats are always present.
+    BenchStats::from_samples(&timers).unwrap()
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
+/// Compare the result with the accepted answer.
+fn check_result(answer: Option<&str>, year: Year, day: Day, part: u8) -> String {
+    match Answers::read_from_file(year).check(day, part, answer) {
+        AnswerCheck::Correct => " ✔".into(),
+        AnswerCheck::Wrong { expected } => format!(" ✘ (expected {expected})"),
+        AnswerCheck::Unknown => " ?".into(),
+    }
 }
 
 fn format_duration(duration: &Duration, samples: u128) -> String {
@@ -90,71 +344,85 @@ fn format_duration(duration: &Duration, samples: u128) -> String {
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn print_result(outcome: &PartOutcome, part: &str, duration_str: &str, check_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
-    match result {
-        Some(result) => {
-            if result.to_string().contains('\n') {
-                let str = format!("{part}: ▼ {duration_str}");
+    match outcome {
+        PartOutcome::Solved(result) => {
+            if result.contains('\n') {
+                let str = format!("{part}: ▼ {duration_str}{check_str}");
                 if is_intermediate_result {
-                    print!("{str}");
+                    out!("{str}");
                 } else {
-                    print!("\r");
-                    println!("{str}");
-                    println!("{result}");
+                    out!("\r");
+                    outln!("{str}");
+                    outln!("{result}");
                 }
             } else {
-                let str = format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}");
+                let str =
+                    format!("{part}: {ANSI_BOLD}{result}{ANSI_RESET}{duration_str}{check_str}");
                 if is_intermediate_result {
-                    print!("{str}");
+                    out!("{str}");
                 } else {
-                    print!("\r");
-                    println!("{str}");
+                    out!("\r");
+                    outln!("{str}");
                 }
             }
         }
-        None => {
+        PartOutcome::Unsolved => {
+            if is_intermediate_result {
+                out!("{part}: ✖");
+            } else {
+                out!("\r");
+                outln!("{part}: ✖{check_str}             ");
+            }
+        }
+        PartOutcome::Failed(e) => {
             if is_intermediate_result {
-                print!("{part}: ✖");
+                out!("{part}: ✖ {e}");
             } else {
-                print!("\r");
-                println!("{part}: ✖             ");
+                out!("\r");
+                outln!("{part}: ✖ {ANSI_RED}{e}{ANSI_RESET}{duration_str}{check_str}");
             }
         }
     }
 }
 
-/// Parse the arguments passed to `solve` and try to submit one part of the solution if:
-///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
-    let args: Vec<String> = env::args().collect();
-
-    if !args.contains(&"--submit".into()) {
-        return None;
-    }
+/// Try to submit one part of the solution, natively or via aoc-cli if no session cookie is set.
+fn submit_result(result: &str, year: Year, day: Day, part: u8) {
+    let mut answers = Answers::read_from_file(year);
 
-    if args.len() < 3 {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    if let Err(reason) = answers.validate_submission(day, part, result) {
+        eprintln!("Not submitting: {reason}");
+        return;
     }
 
-    let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
-
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    let outcome = match Client::from_env() {
+        Ok(client) => {
+            println!("Submitting result...");
+            match client.submit(year, day, part, result) {
+                Ok((outcome, message)) => {
+                    print!("{message}");
+                    outcome
+                }
+                Err(e) => {
+                    eprintln!("failed to submit result: {e}");
+                    return;
+                }
+            }
+        }
+        // without a session cookie, aoc-cli may still be configured.
+        Err(ClientError::SessionNotFound) => submit_with_aoc_cli(result, year, day, part),
+        Err(e) => {
+            eprintln!("failed to submit result: {e}");
+            return;
+        }
     };
 
-    if part_submit != part {
-        return None;
-    }
+    record_submission(&mut answers, year, day, part, result, &outcome);
+}
 
+fn submit_with_aoc_cli(result: &str, year: Year, day: Day, part: u8) -> SubmissionOutcome {
     if aoc_cli::check().is_err() {
         eprintln!(
             "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
@@ -163,5 +431,44 @@ fn submit_result<T: Display>(
     }
 
     println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    match aoc_cli::submit(year, day, part, result) {
+        Ok(output) | Err(aoc_cli::AocCommandError::BadExitStatus(output)) => {
+            aoc_cli::submission_outcome(&output)
+        }
+        Err(_) => SubmissionOutcome::Unknown,
+    }
+}
+
+/// Persist what we learned from a submission, so known-wrong answers are not submitted twice.
+fn record_submission(
+    answers: &mut Answers,
+    year: Year,
+    day: Day,
+    part: u8,
+    result: &str,
+    outcome: &SubmissionOutcome,
+) {
+    match outcome {
+        SubmissionOutcome::Correct => answers.set(day, part, result),
+        SubmissionOutcome::TooHigh => {
+            answers.add_wrong_guess(day, part, result, Some(Hint::TooHigh));
+        }
+        SubmissionOutcome::TooLow => {
+            answers.add_wrong_guess(day, part, result, Some(Hint::TooLow));
+        }
+        SubmissionOutcome::Wrong => answers.add_wrong_guess(day, part, result, None),
+        SubmissionOutcome::Wait(seconds) => {
+            match seconds {
+                Some(seconds) => eprintln!("Rate limited, try again in {seconds}s."),
+                None => eprintln!("Rate limited, try again later."),
+            }
+            return;
+        }
+        SubmissionOutcome::AlreadySolved | SubmissionOutcome::Unknown => return,
+    }
+
+    match answers.store_file(year) {
+        Ok(()) => println!("Stored submission outcome in answers file."),
+        Err(e) => eprintln!("Failed to store submission outcome: {e}"),
+    }
 }
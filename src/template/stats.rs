@@ -0,0 +1,261 @@
+/// Summary statistics over the samples collected while benching a solution part.
+use std::{collections::HashMap, fmt::Display, str::FromStr, time::Duration};
+
+use tinyjson::JsonValue;
+
+/// Represents the distribution of bench samples for a single part.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BenchStats {
+    pub samples: u128,
+    pub mean: Duration,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub std_dev: Duration,
+    /// Number of samples outside of 1.5 times the interquartile range.
+    pub outliers: u64,
+}
+
+impl BenchStats {
+    /// Computes statistics over a set of samples. Returns [`None`] if there are no samples.
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut nanos: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        nanos.sort_unstable_by(f64::total_cmp);
+
+        let len = nanos.len() as f64;
+        let mean = nanos.iter().sum::<f64>() / len;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / len;
+
+        let q1 = percentile(&nanos, 0.25);
+        let q3 = percentile(&nanos, 0.75);
+        let iqr = q3 - q1;
+        let (low, high) = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+
+        Some(BenchStats {
+            samples: samples.len() as u128,
+            mean: from_nanos(mean),
+            min: from_nanos(nanos[0]),
+            median: from_nanos(percentile(&nanos, 0.5)),
+            p95: from_nanos(percentile(&nanos, 0.95)),
+            std_dev: from_nanos(variance.sqrt()),
+            outliers: nanos.iter().filter(|&&x| x < low || x > high).count() as u64,
+        })
+    }
+
+    /// Returns the value of the selected statistic.
+    pub fn get(&self, statistic: Statistic) -> Duration {
+        match statistic {
+            Statistic::Mean => self.mean,
+            Statistic::Min => self.min,
+            Statistic::Median => self.median,
+            Statistic::P95 => self.p95,
+        }
+    }
+}
+
+impl Display for BenchStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {:.1?} · median {:.1?} · p95 {:.1?} · σ {:.1?} · {} outliers",
+            self.min, self.median, self.p95, self.std_dev, self.outliers
+        )
+    }
+}
+
+/// Linear interpolation between the closest ranks of a sorted slice.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    #[allow(clippy::cast_precision_loss)]
+    let rank = p * (sorted.len() - 1) as f64;
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - rank.floor())
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn from_nanos(nanos: f64) -> Duration {
+    Duration::from_nanos(nanos.round() as u64)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The statistic used to summarize benchmarks, e.g. in the readme table.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Statistic {
+    #[default]
+    Mean,
+    Min,
+    Median,
+    P95,
+}
+
+impl Display for Statistic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Statistic::Mean => "mean",
+            Statistic::Min => "min",
+            Statistic::Median => "median",
+            Statistic::P95 => "p95",
+        })
+    }
+}
+
+impl FromStr for Statistic {
+    type Err = StatisticFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "mean" => Ok(Statistic::Mean),
+            "min" => Ok(Statistic::Min),
+            "median" => Ok(Statistic::Median),
+            "p95" => Ok(Statistic::P95),
+            _ => Err(StatisticFromStrError),
+        }
+    }
+}
+
+/// An error which can be returned when parsing a [`Statistic`].
+#[derive(Debug)]
+pub struct StatisticFromStrError;
+
+impl std::error::Error for StatisticFromStrError {}
+
+impl Display for StatisticFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting one of `mean`, `min`, `median` or `p95`")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&BenchStats> for JsonValue {
+    #[allow(clippy::cast_precision_loss)]
+    fn from(value: &BenchStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        let mut insert_nanos = |key: &str, d: Duration| {
+            map.insert(key.into(), JsonValue::Number(d.as_nanos() as f64));
+        };
+
+        insert_nanos("mean_nanos", value.mean);
+        insert_nanos("min_nanos", value.min);
+        insert_nanos("median_nanos", value.median);
+        insert_nanos("p95_nanos", value.p95);
+        insert_nanos("std_dev_nanos", value.std_dev);
+
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert("outliers".into(), JsonValue::Number(value.outliers as f64));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for BenchStats {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected stats.{key} to be a number."))
+        };
+
+        Ok(BenchStats {
+            samples: number("samples")? as u128,
+            mean: from_nanos(number("mean_nanos")?),
+            min: from_nanos(number("min_nanos")?),
+            median: from_nanos(number("median_nanos")?),
+            p95: from_nanos(number("p95_nanos")?),
+            std_dev: from_nanos(number("std_dev_nanos")?),
+            outliers: number("outliers")? as u64,
+        })
+    }
+}
+
+/// Reads optional stats stored under `key`. Missing keys and `null` yield [`None`].
+pub fn stats_from_json(
+    json: &HashMap<String, JsonValue>,
+    key: &str,
+) -> Result<Option<BenchStats>, String> {
+    match json.get(key) {
+        None => Ok(None),
+        Some(v) if v.is_null() => Ok(None),
+        Some(v) => BenchStats::try_from(v).map(Some),
+    }
+}
+
+/// Converts optional stats to JSON, using `null` for [`None`].
+pub fn stats_to_json(stats: Option<&BenchStats>) -> JsonValue {
+    match stats {
+        Some(stats) => JsonValue::from(stats),
+        None => JsonValue::Null,
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use tinyjson::JsonValue;
+
+    use super::{BenchStats, Statistic};
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|x| Duration::from_nanos(*x)).collect()
+    }
+
+    #[test]
+    fn computes_stats() {
+        let stats = BenchStats::from_samples(&nanos(&[10, 12, 11, 13, 10, 11, 12, 100])).unwrap();
+        assert_eq!(stats.samples, 8);
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.median, Duration::from_nanos(12));
+        assert_eq!(stats.mean, Duration::from_nanos(22));
+        assert_eq!(stats.outliers, 1);
+        assert!(stats.p95 > Duration::from_nanos(13));
+    }
+
+    #[test]
+    fn handles_single_sample() {
+        let stats = BenchStats::from_samples(&nanos(&[42])).unwrap();
+        assert_eq!(stats.median, Duration::from_nanos(42));
+        assert_eq!(stats.p95, Duration::from_nanos(42));
+        assert_eq!(stats.std_dev, Duration::ZERO);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn handles_no_samples() {
+        assert!(BenchStats::from_samples(&[]).is_none());
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let stats = BenchStats::from_samples(&nanos(&[1, 2, 3, 4, 50])).unwrap();
+        let json = JsonValue::from(&stats);
+        assert_eq!(BenchStats::try_from(&json).unwrap(), stats);
+    }
+
+    #[test]
+    fn parses_statistics() {
+        assert_eq!("median".parse::<Statistic>().unwrap(), Statistic::Median);
+        assert_eq!("p95".parse::<Statistic>().unwrap(), Statistic::P95);
+        assert!("p99".parse::<Statistic>().is_err());
+    }
+}
@@ -0,0 +1,339 @@
+/// Templates that `scaffold` renders new solutions from.
+///
+/// Besides the built-in `src/template.txt`, templates can be defined in the `templates/` directory:
+/// either as a single file `templates/<name>.txt`, or as a directory `templates/<name>/` holding
+/// the solution in `module.txt` and extra files at their rendered path relative to the repository,
+/// e.g. `templates/<name>/data/%YEAR%/examples/%DAY%-2.txt`.
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::template::{Day, Year, examples::Examples};
+
+const MODULE_TEMPLATE: &str =
+    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+
+pub const TEMPLATES_DIR: &str = "templates";
+const DEFAULT_TEMPLATE: &str = "default";
+const MODULE_FILE_NAME: &str = "module.txt";
+
+/// A solution template, with the paths and contents of extra files to create.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Template {
+    pub module: String,
+    pub extra_files: Vec<(String, String)>,
+}
+
+impl Template {
+    /// Loads the template with the provided name from [`TEMPLATES_DIR`].
+    ///
+    /// Without a name, `templates/default` is used if present, or the built-in template otherwise.
+    pub fn load(name: Option<&str>) -> Result<Self, String> {
+        let dir = Path::new(TEMPLATES_DIR);
+
+        match name {
+            Some(name) => Self::load_from(dir, name)?.ok_or_else(|| {
+                let available = list(dir);
+                if available.is_empty() {
+                    format!("template \"{name}\" not found in \"{TEMPLATES_DIR}/\".")
+                } else {
+                    format!(
+                        "template \"{name}\" not found. Available templates: {}.",
+                        available.join(", ")
+                    )
+                }
+            }),
+            None => Ok(Self::load_from(dir, DEFAULT_TEMPLATE)?.unwrap_or_else(Self::builtin)),
+        }
+    }
+
+    pub fn builtin() -> Self {
+        Self {
+            module: MODULE_TEMPLATE.into(),
+            extra_files: vec![],
+        }
+    }
+
+    fn load_from(dir: &Path, name: &str) -> Result<Option<Self>, String> {
+        let file = dir.join(format!("{name}.txt"));
+        if file.is_file() {
+            return Ok(Some(Self {
+                module: read(&file)?,
+                extra_files: vec![],
+            }));
+        }
+
+        let template_dir = dir.join(name);
+        if !template_dir.is_dir() {
+            return Ok(None);
+        }
+
+        let module = read(&template_dir.join(MODULE_FILE_NAME))?;
+        let mut extra_files = vec![];
+
+        for path in walk(&template_dir)? {
+            let relative = path.strip_prefix(&template_dir).unwrap();
+            if relative == Path::new(MODULE_FILE_NAME) {
+                continue;
+            }
+
+            let relative = relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+
+            extra_files.push((relative, read(&path)?));
+        }
+
+        extra_files.sort();
+        Ok(Some(Self {
+            module,
+            extra_files,
+        }))
+    }
+}
+
+/// Lists the names of the templates in a directory.
+fn list(dir: &Path) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return vec![];
+    };
+
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.is_dir() {
+                return Some(path.file_name()?.to_str()?.to_string());
+            }
+            (path.extension()? == "txt").then(|| path.file_stem()?.to_str().map(String::from))?
+        })
+        .collect();
+
+    names.sort();
+    names
+}
+
+fn walk(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let mut files = vec![];
+    let entries =
+        fs::read_dir(dir).map_err(|e| format!("failed to read \"{}\": {e}", dir.display()))?;
+
+    for entry in entries {
+        let path = entry.map_err(|e| e.to_string())?.path();
+        if path.is_dir() {
+            files.extend(walk(&path)?);
+        } else {
+            files.push(path);
+        }
+    }
+
+    Ok(files)
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("failed to read \"{}\": {e}", path.display()))
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The values that are substituted for `%PLACEHOLDER%`s in templates.
+#[derive(Clone, Debug)]
+pub struct Placeholders(Vec<(&'static str, String)>);
+
+impl Placeholders {
+    pub fn new(year: Year, day: Day, puzzle: Option<&str>, examples: &Examples) -> Self {
+        let part_two_example = if examples.separate_part_2_input().is_some() {
+            "read_file_part(\"examples\", YEAR, DAY, 2)"
+        } else {
+            "read_file(\"examples\", YEAR, DAY)"
+        };
+
+        let title = puzzle
+            .and_then(extract_title)
+            .unwrap_or_else(|| format!("Day {}", day.into_inner()));
+
+        let example_input = examples.part_1.input.clone().unwrap_or_default();
+        let part_two_example_input = examples
+            .part_2
+            .input
+            .clone()
+            .unwrap_or_else(|| example_input.clone());
+
+        Self(vec![
+            ("%DAY_NUMBER%", day.into_inner().to_string()),
+            ("%DAY%", day.to_string()),
+            ("%YEAR%", year.to_string()),
+            ("%TITLE%", title),
+            ("%DATE%", format!("{year}-12-{day}")),
+            (
+                "%PART_ONE_ANSWER%",
+                format_answer(examples.part_1.answer.as_deref()),
+            ),
+            (
+                "%PART_TWO_ANSWER%",
+                format_answer(examples.part_2.answer.as_deref()),
+            ),
+            ("%PART_TWO_EXAMPLE%", part_two_example.into()),
+            ("%EXAMPLE_INPUT%", example_input),
+            ("%PART_TWO_EXAMPLE_INPUT%", part_two_example_input),
+        ])
+    }
+
+    pub fn render(&self, template: &str) -> String {
+        // substitute in a single pass, so values (e.g. example inputs) are never substituted again.
+        let mut out = String::with_capacity(template.len());
+        let mut rest = template;
+
+        'outer: while let Some(start) = rest.find('%') {
+            out.push_str(&rest[..start]);
+            rest = &rest[start..];
+
+            for (placeholder, value) in &self.0 {
+                if let Some(remaining) = rest.strip_prefix(placeholder) {
+                    out.push_str(value);
+                    rest = remaining;
+                    continue 'outer;
+                }
+            }
+
+            out.push('%');
+            rest = &rest[1..];
+        }
+
+        out.push_str(rest);
+        out
+    }
+}
+
+/// Renders an example answer as the expected value of a test. Answers that are not integers are
+/// rendered as `String`, so the test does not compile until the part returns one.
+fn format_answer(answer: Option<&str>) -> String {
+    let Some(answer) = answer else {
+        return "None".into();
+    };
+
+    match (answer.parse::<u64>(), answer.parse::<i64>()) {
+        (Ok(answer), _) => format!("Some({answer})"),
+        (_, Ok(answer)) => format!("Some({answer})"),
+        _ => format!("Some(String::from({answer:?}))"),
+    }
+}
+
+/// Extracts the title from a heading like `## --- Day 3: Lobby ---`.
+fn extract_title(puzzle: &str) -> Option<String> {
+    puzzle.lines().find_map(|line| {
+        let title = line
+            .trim_start_matches('#')
+            .trim()
+            .strip_prefix("--- ")?
+            .strip_suffix(" ---")?;
+        title.starts_with("Day ").then(|| title.to_string())
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs, process};
+
+    use super::{Placeholders, Template, extract_title, format_answer, list};
+    use crate::{
+        day,
+        template::{
+            Year,
+            examples::{Example, Examples},
+        },
+    };
+
+    const YEAR: Year = Year::new(2025).unwrap();
+
+    #[test]
+    fn renders_placeholders() {
+        let examples = Examples {
+            part_1: Example {
+                input: Some("100%DAY%\n".into()),
+                answer: Some("357".into()),
+            },
+            part_2: Example::default(),
+        };
+        let placeholders = Placeholders::new(
+            YEAR,
+            day!(3),
+            Some("## --- Day 3: Lobby ---\n\nHi.\n"),
+            &examples,
+        );
+
+        assert_eq!(
+            placeholders.render(
+                "solution!(%DAY_NUMBER%); // %TITLE%, %DATE%, src/bin/%YEAR%_%DAY%.rs, 50% %UNKNOWN%"
+            ),
+            "solution!(3); // Day 3: Lobby, 2025-12-03, src/bin/2025_03.rs, 50% %UNKNOWN%"
+        );
+        assert_eq!(
+            placeholders.render("%PART_ONE_ANSWER% %PART_TWO_ANSWER%"),
+            "Some(357) None"
+        );
+        assert_eq!(
+            placeholders.render("%PART_TWO_EXAMPLE_INPUT%"),
+            "100%DAY%\n"
+        );
+    }
+
+    #[test]
+    fn renders_answers_by_type() {
+        assert_eq!(format_answer(Some("357")), "Some(357)");
+        assert_eq!(format_answer(Some("-12")), "Some(-12)");
+        assert_eq!(format_answer(Some("+5")), "Some(5)");
+        assert_eq!(format_answer(Some("6,1")), "Some(String::from(\"6,1\"))");
+        assert_eq!(format_answer(None), "None");
+    }
+
+    #[test]
+    fn falls_back_to_day_as_title() {
+        let placeholders = Placeholders::new(YEAR, day!(12), None, &Examples::default());
+        assert_eq!(placeholders.render("%TITLE%"), "Day 12");
+        assert_eq!(extract_title("## --- Part Two ---"), None);
+    }
+
+    #[test]
+    fn loads_builtin_template() {
+        let template = Template::builtin();
+        assert!(template.module.contains("%DAY_NUMBER%"));
+        assert!(template.extra_files.is_empty());
+    }
+
+    #[test]
+    fn loads_directory_templates() {
+        let dir = env::temp_dir().join(format!("aoc-templates-{}", process::id()));
+        let examples_dir = dir
+            .join("grid")
+            .join("data")
+            .join("%YEAR%")
+            .join("examples");
+        fs::create_dir_all(&examples_dir).unwrap();
+        fs::write(dir.join("grid").join("module.txt"), "grid %DAY%").unwrap();
+        fs::write(examples_dir.join("%DAY%-2.txt"), "%EXAMPLE_INPUT%").unwrap();
+        fs::write(dir.join("parser.txt"), "parser %DAY%").unwrap();
+
+        let grid = Template::load_from(&dir, "grid").unwrap().unwrap();
+        let parser = Template::load_from(&dir, "parser").unwrap().unwrap();
+        let missing = Template::load_from(&dir, "missing").unwrap();
+        let names = list(&dir);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(grid.module, "grid %DAY%");
+        assert_eq!(
+            grid.extra_files,
+            vec![(
+                "data/%YEAR%/examples/%DAY%-2.txt".to_string(),
+                "%EXAMPLE_INPUT%".to_string()
+            )]
+        );
+        assert_eq!(parser.module, "parser %DAY%");
+        assert_eq!(missing, None);
+        assert_eq!(names, vec!["grid", "parser"]);
+    }
+}
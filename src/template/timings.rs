@@ -1,9 +1,12 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{collections::HashMap, fs, io::Error, path::PathBuf, str::FromStr};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
-
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+use crate::template::{
+    Day, Year, get_data_dir,
+    limits::{RunFailure, failure_from_json, failure_to_json},
+    report::PARSE_PART,
+    stats::{BenchStats, Statistic, stats_from_json, stats_to_json},
+};
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
@@ -11,9 +14,90 @@ pub struct Timing {
     pub day: Day,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
+    pub part_1_stats: Option<BenchStats>,
+    pub part_2_stats: Option<BenchStats>,
+    /// Set if the run of a part was stopped by a limit.
+    pub part_1_failure: Option<RunFailure>,
+    pub part_2_failure: Option<RunFailure>,
+    /// Set if the solution parses its input separately, see `solution!(N, parse = ..)`.
+    pub parse: Option<String>,
+    pub parse_stats: Option<BenchStats>,
     pub total_nanos: f64,
 }
 
+impl Timing {
+    /// Creates a timing of a day without any timed parts.
+    pub fn new(day: Day) -> Self {
+        Self {
+            day,
+            part_1: None,
+            part_2: None,
+            part_1_stats: None,
+            part_2_stats: None,
+            part_1_failure: None,
+            part_2_failure: None,
+            parse: None,
+            parse_stats: None,
+            total_nanos: 0_f64,
+        }
+    }
+
+    /// Formats the selected statistic of a part, or of the parsing for [`PARSE_PART`]. Falls back
+    /// to the stored mean if the timing predates statistics. Parts that were stopped by a limit
+    /// show the reason instead.
+    pub fn part_display(&self, part: u8, statistic: Statistic) -> Option<String> {
+        let (timing, stats, failure) = match part {
+            PARSE_PART => (&self.parse, &self.parse_stats, None),
+            1 => (&self.part_1, &self.part_1_stats, self.part_1_failure),
+            2 => (&self.part_2, &self.part_2_stats, self.part_2_failure),
+            _ => return None,
+        };
+
+        if let Some(failure) = failure {
+            return Some(failure.label().to_string());
+        }
+
+        match (statistic, stats) {
+            (Statistic::Mean, _) | (_, None) => timing.clone(),
+            (statistic, Some(stats)) => Some(format!("{:.1?}", stats.get(statistic))),
+        }
+    }
+
+    /// The selected statistic of a part in nanoseconds. Falls back to parsing the stored mean if
+    /// the timing predates statistics.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn part_nanos(&self, part: u8, statistic: Statistic) -> Option<f64> {
+        let (timing, stats) = match part {
+            PARSE_PART => (&self.parse, &self.parse_stats),
+            1 => (&self.part_1, &self.part_1_stats),
+            2 => (&self.part_2, &self.part_2_stats),
+            _ => return None,
+        };
+
+        match stats {
+            Some(stats) => Some(stats.get(statistic).as_nanos() as f64),
+            None => parse_duration_nanos(timing.as_deref()?),
+        }
+    }
+
+    /// Sum of the selected statistic over the parsing and both parts, in nanoseconds. Parts
+    /// without statistics count with their stored mean, see [`Timing::part_nanos`].
+    pub fn nanos(&self, statistic: Statistic) -> f64 {
+        let has_stats = [&self.parse_stats, &self.part_1_stats, &self.part_2_stats]
+            .iter()
+            .any(|s| s.is_some());
+
+        if statistic == Statistic::Mean || !has_stats {
+            return self.total_nanos;
+        }
+
+        [PARSE_PART, 1, 2]
+            .into_iter()
+            .filter_map(|part| self.part_nanos(part, statistic))
+            .sum()
+    }
+}
+
 /// Represents benchmark times for a set of days.
 /// Can be serialized from / to JSON.
 #[derive(Clone, Debug, Default)]
@@ -22,16 +106,17 @@ pub struct Timings {
 }
 
 impl Timings {
-    /// Dehydrate timings to a JSON file.
-    pub fn store_file(&self) -> Result<(), Error> {
+    /// Dehydrate timings to the JSON file of an event.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        fs::create_dir_all(get_data_dir(year, ""))?;
+        let mut file = fs::File::create(get_path(year))?;
         json.format_to(&mut file)
     }
 
-    /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
+    /// Rehydrate timings from the JSON file of an event. If not present, returns empty timings.
+    pub fn read_from_file(year: Year) -> Self {
+        fs::read_to_string(get_path(year))
             .map_err(|x| x.to_string())
             .and_then(Timings::try_from)
             .unwrap_or_default()
@@ -51,13 +136,18 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
     /// Sum up total duration of timings as millis.
     pub fn total_millis(&self) -> f64 {
-        self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
+        self.total_millis_by(Statistic::Mean)
+    }
+
+    /// Sum up the selected statistic of timings as millis.
+    pub fn total_millis_by(&self, statistic: Statistic) -> f64 {
+        self.data.iter().map(|x| x.nanos(statistic)).sum::<f64>() / 1_000_000_f64
     }
 
     pub fn is_day_complete(&self, day: Day) -> bool {
@@ -65,6 +155,73 @@ impl Timings {
             .iter()
             .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
     }
+
+    /// Compare `self` against a baseline, part by part (including the parsing).
+    /// Parts that are missing in either set of timings are skipped.
+    pub fn compare(&self, baseline: &Self, statistic: Statistic) -> Vec<PartChange> {
+        let mut changes = vec![];
+
+        for timing in &self.data {
+            let Some(before) = baseline.data.iter().find(|t| t.day == timing.day) else {
+                continue;
+            };
+
+            for part in [PARSE_PART, 1, 2] {
+                if let (Some(before), Some(after)) = (
+                    before.part_nanos(part, statistic),
+                    timing.part_nanos(part, statistic),
+                ) {
+                    changes.push(PartChange {
+                        day: timing.day,
+                        part,
+                        before,
+                        after,
+                    });
+                }
+            }
+        }
+
+        changes
+    }
+}
+
+/// Represents the change of a part's runtime between two sets of timings.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartChange {
+    pub day: Day,
+    pub part: u8,
+    pub before: f64,
+    pub after: f64,
+}
+
+impl PartChange {
+    /// Relative change in percent. Positive values mean the part got slower.
+    pub fn percent(&self) -> f64 {
+        if self.before == 0_f64 {
+            return 0_f64;
+        }
+        (self.after - self.before) / self.before * 100_f64
+    }
+}
+
+fn get_path(year: Year) -> PathBuf {
+    get_data_dir(year, "").join("timings.json")
+}
+
+/// Parses a duration formatted with `{:?}`, e.g. `74.1ns` or `1.3ms`, into nanoseconds.
+fn parse_duration_nanos(s: &str) -> Option<f64> {
+    // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
+    let parse = |postfix: &str| s.strip_suffix(postfix)?.parse::<f64>().ok();
+
+    if s.ends_with("ns") {
+        parse("ns")
+    } else if s.ends_with("µs") {
+        parse("µs").map(|x| x * 1000_f64)
+    } else if s.ends_with("ms") {
+        parse("ms").map(|x| x * 1_000_000_f64)
+    } else {
+        parse("s").map(|x| x * 1_000_000_000_f64)
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -133,6 +290,34 @@ impl From<&Timing> for JsonValue {
             },
         );
 
+        map.insert(
+            "part_1_stats".into(),
+            stats_to_json(value.part_1_stats.as_ref()),
+        );
+        map.insert(
+            "part_2_stats".into(),
+            stats_to_json(value.part_2_stats.as_ref()),
+        );
+        map.insert(
+            "part_1_failure".into(),
+            failure_to_json(value.part_1_failure),
+        );
+        map.insert(
+            "part_2_failure".into(),
+            failure_to_json(value.part_2_failure),
+        );
+        map.insert(
+            "parse".into(),
+            match value.parse.clone() {
+                Some(x) => JsonValue::String(x),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert(
+            "parse_stats".into(),
+            stats_to_json(value.parse_stats.as_ref()),
+        );
+
         JsonValue::Object(map)
     }
 }
@@ -166,10 +351,33 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        // NOTE: timings stored before statistics were collected do not have these keys.
+        let part_1_stats = stats_from_json(json, "part_1_stats")?;
+        let part_2_stats = stats_from_json(json, "part_2_stats")?;
+        let part_1_failure = failure_from_json(json, "part_1_failure")?;
+        let part_2_failure = failure_from_json(json, "part_2_failure")?;
+
+        // NOTE: only solutions that parse separately have these keys.
+        let parse = match json.get("parse") {
+            None => None,
+            Some(v) if v.is_null() => None,
+            Some(v) => Some(
+                v.get::<String>()
+                    .ok_or("Expected timing.parse to be null or string.")?,
+            ),
+        };
+        let parse_stats = stats_from_json(json, "parse_stats")?;
+
         Ok(Timing {
             day,
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
+            part_1_stats,
+            part_2_stats,
+            part_1_failure,
+            part_2_failure,
+            parse: parse.cloned(),
+            parse_stats,
             total_nanos,
         })
     }
@@ -187,22 +395,21 @@ mod tests {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
+                    ..Timing::new(day!(1))
                 },
                 Timing {
-                    day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
+                    ..Timing::new(day!(2))
                 },
                 Timing {
-                    day: day!(4),
                     part_1: Some("40ms".into()),
-                    part_2: None,
                     total_nanos: 4e+10,
+                    ..Timing::new(day!(4))
                 },
             ],
         }
@@ -278,42 +485,39 @@ mod tests {
         fn handles_completed_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
                     total_nanos: 3_000_000_000_f64,
+                    ..Timing::new(day!(1))
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
         fn handles_partial_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
                     part_1: Some("1ms".into()),
-                    part_2: None,
                     total_nanos: 1_000_000_000_f64,
+                    ..Timing::new(day!(1))
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
 
         #[test]
         fn handles_uncompleted_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
-                    part_1: None,
-                    part_2: None,
                     total_nanos: 0.0,
+                    ..Timing::new(day!(1))
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
     }
 
@@ -330,10 +534,8 @@ mod tests {
             let timings = get_mock_timings();
             let other = Timings {
                 data: vec![Timing {
-                    day: day!(3),
-                    part_1: None,
-                    part_2: None,
                     total_nanos: 0_f64,
+                    ..Timing::new(day!(3))
                 }],
             };
             let merged = timings.merge(&other);
@@ -350,10 +552,8 @@ mod tests {
 
             let other = Timings {
                 data: vec![Timing {
-                    day: day!(2),
-                    part_1: None,
-                    part_2: None,
                     total_nanos: 0_f64,
+                    ..Timing::new(day!(2))
                 }],
             };
             let merged = timings.merge(&other);
@@ -381,4 +581,75 @@ mod tests {
             assert_eq!(merged.data.len(), 3);
         }
     }
+
+    mod nanos {
+        use std::time::Duration;
+
+        use crate::{
+            day,
+            template::{Statistic, stats::BenchStats, timings::Timing},
+        };
+
+        #[test]
+        fn falls_back_to_mean_for_parts_without_stats() {
+            let samples = [Duration::from_millis(2); 10];
+            let timing = Timing {
+                part_1: Some("1.0ms".into()),
+                part_2: Some("2.0ms".into()),
+                part_2_stats: BenchStats::from_samples(&samples),
+                total_nanos: 3e+6,
+                ..Timing::new(day!(1))
+            };
+
+            assert_eq!(timing.nanos(Statistic::Median), 3e+6);
+        }
+    }
+
+    mod compare {
+        use crate::{
+            day,
+            template::{
+                Statistic,
+                timings::{Timing, Timings},
+            },
+        };
+
+        use super::get_mock_timings;
+
+        #[test]
+        fn compares_overlapping_parts() {
+            let baseline = get_mock_timings();
+            let timings = Timings {
+                data: vec![
+                    Timing {
+                        part_1: Some("15ms".into()),
+                        part_2: Some("60ms".into()),
+                        total_nanos: 7.5e+7,
+                        ..Timing::new(day!(2))
+                    },
+                    Timing {
+                        part_1: Some("40ms".into()),
+                        part_2: Some("1.5µs".into()),
+                        total_nanos: 4e+7,
+                        ..Timing::new(day!(4))
+                    },
+                ],
+            };
+
+            let changes = timings.compare(&baseline, Statistic::Mean);
+            assert_eq!(changes.len(), 3);
+            assert_eq!(changes[0].day, day!(2));
+            assert_eq!(changes[0].percent(), -50_f64);
+            assert_eq!(changes[1].percent(), 50_f64);
+            assert_eq!(changes[2].day, day!(4));
+            assert_eq!(changes[2].part, 1);
+            assert_eq!(changes[2].percent(), 0_f64);
+        }
+
+        #[test]
+        fn handles_empty_baseline() {
+            let changes = get_mock_timings().compare(&Timings::default(), Statistic::Mean);
+            assert!(changes.is_empty());
+        }
+    }
 }
@@ -0,0 +1,204 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::template::{AllDays, Day};
+
+include!("event.rs");
+
+/// The year of an Advent of Code event (i.e. 2015 or later).
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::template::Year;
+/// let year = Year::new(2025).unwrap();
+/// assert_eq!(year.to_string(), "2025")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// The year configured with `AOC_YEAR` at compile time. Falls back to 2025 if not set.
+    pub const CONFIGURED: Self = match Self::new(configured_year(option_env!("AOC_YEAR"))) {
+        Some(year) => year,
+        None => panic!("AOC_YEAR is not a valid year of advent"),
+    };
+
+    /// Creates a [`Year`] from the provided value if an event took place in it,
+    /// returns [`None`] otherwise.
+    pub const fn new(year: u16) -> Option<Self> {
+        if year < 2015 {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    /// Returns the year set with `AOC_YEAR` at runtime, e.g. by `--year`, or the
+    /// [`Year::CONFIGURED`] year otherwise.
+    pub fn current() -> Self {
+        std::env::var("AOC_YEAR")
+            .ok()
+            .and_then(|year| year.parse().ok())
+            .unwrap_or(Self::CONFIGURED)
+    }
+
+    /// Returns the year of a solution binary named like `2025_01`, or the
+    /// [`Year::CONFIGURED`] year if the name does not start with one.
+    pub const fn from_bin_name(name: Option<&str>) -> Self {
+        let Some(name) = name else {
+            return Self::CONFIGURED;
+        };
+
+        let bytes = name.as_bytes();
+        if bytes.len() < 5 || bytes[4] != b'_' {
+            return Self::CONFIGURED;
+        }
+
+        let mut year = 0;
+        let mut i = 0;
+        while i < 4 {
+            if !bytes[i].is_ascii_digit() {
+                return Self::CONFIGURED;
+            }
+            year = year * 10 + (bytes[i] - b'0') as u16;
+            i += 1;
+        }
+
+        match Self::new(year) {
+            Some(year) => year,
+            None => Self::CONFIGURED,
+        }
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// Returns the last day of the event. Starting with 2025, the event runs for 12 days
+    /// instead of 25.
+    pub const fn last_day(self) -> u8 {
+        last_day(self.0)
+    }
+
+    /// Returns `true` if the day is part of the event.
+    pub fn contains(self, day: Day) -> bool {
+        day <= self.last_day()
+    }
+
+    /// Parses a day of the event, e.g. a command-line argument. Unlike [`Day::from_str`], this
+    /// rejects days after the [`Year::last_day`].
+    pub fn parse_day(self, s: &str) -> Result<Day, DayOfYearError> {
+        s.parse::<Day>()
+            .ok()
+            .filter(|day| self.contains(*day))
+            .ok_or(DayOfYearError(self))
+    }
+
+    /// An iterator that yields every day of the event.
+    pub fn days(self) -> AllDays {
+        AllDays::new(self.last_day())
+    }
+
+    /// Returns the name of the solution binary for a day, e.g. `2025_01`.
+    pub fn bin_name(self, day: Day) -> String {
+        format!("{self}_{day}")
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}", self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a year of advent, i.e. 2015 or later")
+    }
+}
+
+/// An error which can be returned when parsing a day of an event, see [`Year::parse_day`].
+#[derive(Debug)]
+pub struct DayOfYearError(Year);
+
+impl Error for DayOfYearError {}
+
+impl Display for DayOfYearError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "expecting a day number between 1 and {} for the {} event",
+            self.0.last_day(),
+            self.0
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Year;
+    use crate::day;
+
+    #[test]
+    fn last_day_depends_on_year() {
+        assert_eq!(Year(2015).last_day(), 25);
+        assert_eq!(Year(2024).last_day(), 25);
+        assert_eq!(Year(2025).last_day(), 12);
+        assert!(Year(2024).contains(day!(25)));
+        assert!(!Year(2025).contains(day!(13)));
+        assert_eq!(Year(2025).days().last(), Some(day!(12)));
+    }
+
+    #[test]
+    fn parses_days_of_the_event() {
+        assert_eq!(Year(2025).parse_day("12").ok(), Some(day!(12)));
+        assert_eq!(Year(2024).parse_day("25").ok(), Some(day!(25)));
+        assert_eq!(
+            Year(2025).parse_day("13").unwrap_err().to_string(),
+            "expecting a day number between 1 and 12 for the 2025 event"
+        );
+        assert!(Year(2025).parse_day("0").is_err());
+        assert!(Year(2024).parse_day("26").is_err());
+    }
+
+    #[test]
+    fn parses_years() {
+        assert_eq!("2024".parse::<Year>().ok(), Some(Year(2024)));
+        assert!("2014".parse::<Year>().is_err());
+        assert!("twenty".parse::<Year>().is_err());
+    }
+
+    #[test]
+    fn parses_bin_names() {
+        assert_eq!(Year::from_bin_name(Some("2017_03")), Year(2017));
+        assert_eq!(Year::from_bin_name(Some("03")), Year::CONFIGURED);
+        assert_eq!(
+            Year::from_bin_name(Some("advent_of_code")),
+            Year::CONFIGURED
+        );
+        assert_eq!(Year::from_bin_name(None), Year::CONFIGURED);
+        assert_eq!(Year(2025).bin_name(day!(3)), "2025_03");
+    }
+}